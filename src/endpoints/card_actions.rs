@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use crate::endpoints::request::Request;
+use crate::models::{CardInfo, DueDateSpec, Ease, EmptyOr, Interval};
 
 // Card actions
 
@@ -64,7 +65,64 @@ impl<'a> Request for AreDue<'a> {
     fn get_params(&self) -> Option<&Self::Params> { Some(self) }
 }
 
-// TODO getIntervals
+/// Corresponds to the `getIntervals` action.
+/// Returns the current interval of each given card.
+/// See `GetIntervalHistory` for the complete history of each card.
+#[derive(serde::Serialize)]
+pub struct GetIntervals<'a> {
+    cards: Cow<'a, [u64]>,
+}
+
+impl<'a> GetIntervals<'a> {
+    pub fn new(cards: impl Into<Cow<'a, [u64]>>) -> Self {
+        Self {
+            cards: cards.into(),
+        }
+    }
+}
+
+impl<'a> Default for GetIntervals<'a> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<'a> Request for GetIntervals<'a> {
+    type Params = Self;
+    type Response = Vec<Interval>;
+    fn get_action(&self) -> &'static str { "getIntervals" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `getIntervals` action with `complete` set to `true`.
+/// Returns every interval each given card has had, oldest first.
+#[derive(serde::Serialize)]
+pub struct GetIntervalHistory<'a> {
+    cards: Cow<'a, [u64]>,
+    complete: bool,
+}
+
+impl<'a> GetIntervalHistory<'a> {
+    pub fn new(cards: impl Into<Cow<'a, [u64]>>) -> Self {
+        Self {
+            cards: cards.into(),
+            complete: true,
+        }
+    }
+}
+
+impl<'a> Default for GetIntervalHistory<'a> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<'a> Request for GetIntervalHistory<'a> {
+    type Params = Self;
+    type Response = Vec<Vec<Interval>>;
+    fn get_action(&self) -> &'static str { "getIntervals" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
 
 /// Corresponds to the `findCards` action.
 /// Returns card IDs for cards that match the given query.
@@ -119,26 +177,171 @@ impl<'a> Request for CardsToNotes<'a> {
 }
 
 // TODO cardsModTime
-// TODO cardsInfo
-// TODO forgetCards
-// TODO relearnCards
-// TODO answerCards
+
+/// Corresponds to the `cardsInfo` action.
+/// Returns information about each given card, or `None` for cards that do not exist.
+#[derive(serde::Serialize)]
+pub struct CardsInfo<'a> {
+    cards: Cow<'a, [u64]>,
+}
+
+impl<'a> CardsInfo<'a> {
+    pub fn new(cards: impl Into<Cow<'a, [u64]>>) -> Self {
+        Self {
+            cards: cards.into(),
+        }
+    }
+}
+
+impl<'a> Default for CardsInfo<'a> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<'a> Request for CardsInfo<'a> {
+    type Params = Self;
+    type Response = Vec<EmptyOr<CardInfo>>;
+    fn get_action(&self) -> &'static str { "cardsInfo" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `forgetCards` action.
+/// Resets the given cards to new.
+#[derive(serde::Serialize)]
+pub struct ForgetCards<'a> {
+    cards: Cow<'a, [u64]>,
+
+    #[serde(
+        rename = "restorePosition",
+        skip_serializing_if = "Option::is_none",
+    )]
+    restore_position: Option<bool>,
+
+    #[serde(
+        rename = "resetCounts",
+        skip_serializing_if = "Option::is_none",
+    )]
+    reset_counts: Option<bool>,
+}
+
+impl<'a> ForgetCards<'a> {
+
+    pub fn new(cards: impl Into<Cow<'a, [u64]>>) -> Self {
+        Self {
+            cards: cards.into(),
+            restore_position: None,
+            reset_counts: None,
+        }
+    }
+
+    /// Restore the original position of the cards in the new queue, where possible.
+    pub fn with_restore_position(mut self, restore_position: bool) -> Self {
+        self.restore_position = Some(restore_position);
+        self
+    }
+
+    /// Reset the repetition and lapse counts of the cards.
+    pub fn with_reset_counts(mut self, reset_counts: bool) -> Self {
+        self.reset_counts = Some(reset_counts);
+        self
+    }
+
+}
+
+impl<'a> Default for ForgetCards<'a> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<'a> Request for ForgetCards<'a> {
+    type Params = Self;
+    type Response = ();
+    fn get_action(&self) -> &'static str { "forgetCards" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `relearnCards` action.
+/// Puts the given cards into the relearning queue.
+#[derive(serde::Serialize)]
+pub struct RelearnCards<'a> {
+    cards: Cow<'a, [u64]>,
+}
+
+impl<'a> RelearnCards<'a> {
+    pub fn new(cards: impl Into<Cow<'a, [u64]>>) -> Self {
+        Self {
+            cards: cards.into(),
+        }
+    }
+}
+
+impl<'a> Default for RelearnCards<'a> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<'a> Request for RelearnCards<'a> {
+    type Params = Self;
+    type Response = ();
+    fn get_action(&self) -> &'static str { "relearnCards" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `answerCards` action.
+/// Answers each card ID with the paired `Ease`.
+/// Returns, for each answer, `true` if the card was found and answered.
+#[derive(serde::Serialize)]
+pub struct AnswerCards<'a> {
+    #[serde(serialize_with = "serialize_answers")]
+    answers: Cow<'a, [(u64, Ease)]>,
+}
+
+impl<'a> AnswerCards<'a> {
+    pub fn new(answers: impl Into<Cow<'a, [(u64, Ease)]>>) -> Self {
+        Self {
+            answers: answers.into(),
+        }
+    }
+}
+
+impl<'a> Request for AnswerCards<'a> {
+    type Params = Self;
+    type Response = Vec<bool>;
+    fn get_action(&self) -> &'static str { "answerCards" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+fn serialize_answers<S>(answers: &[(u64, Ease)], serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+
+    #[derive(serde::Serialize)]
+    struct Answer {
+        #[serde(rename = "cardId")]
+        card_id: u64,
+        ease: Ease,
+    }
+
+    serializer.collect_seq(answers.iter().map(|&(card_id, ease)| Answer { card_id, ease }))
+}
 
 /// Corresponds to the `setDueDate` action
+/// Anki's string syntax can be parsed into a `DueDateSpec` with `str::parse`.
 #[derive(serde::Serialize)]
 pub struct SetDueDate<'a> {
     cards: Cow<'a, [u64]>,
-    days: Cow<'a, str>,
+    days: DueDateSpec,
 }
 
 impl<'a> SetDueDate<'a> {
     pub fn new(
         cards: impl Into<Cow<'a, [u64]>>,
-        days: impl Into<Cow<'a, str>>,
+        days: DueDateSpec,
     ) -> Self {
         SetDueDate {
             cards: cards.into(),
-            days: days.into(),
+            days,
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 use crate::endpoints::request::Request;
-use crate::models::DeckStats;
+use crate::models::{DeckConfig, DeckStats, FalseOr};
 
 /// Corresponds to the `deckNames` action
 #[derive(Default)]
@@ -29,7 +29,7 @@ pub struct GetDecks<'a> {
 }
 
 impl<'a> GetDecks<'a> {
-    fn new(cards: impl Into<Cow<'a, [u64]>>) -> Self {
+    pub fn new(cards: impl Into<Cow<'a, [u64]>>) -> Self {
         Self {
             cards: cards.into(),
         }
@@ -103,18 +103,27 @@ impl<'a> Request for ChangeDeck<'a> {
 #[derive(serde::Serialize)]
 pub struct DeleteDecks<'a> {
     /// Names of decks
-    decks: Vec<Cow<'a, str>>
+    decks: Vec<Cow<'a, str>>,
+    #[serde(rename = "cardsToo", skip_serializing_if = "Option::is_none")]
+    cards_too: Option<bool>,
 }
 
 impl<'a> DeleteDecks<'a> {
     pub fn new(decks: impl Into<Vec<Cow<'a, str>>>) -> Self {
         Self {
             decks: decks.into(),
+            cards_too: None,
         }
     }
     pub fn single(deck: impl Into<Cow<'a, str>>) -> Self {
         Self::new([deck.into()])
     }
+    /// Delete the cards in the decks as well.
+    /// Recent versions of Anki-Connect refuse the request unless this is `true`.
+    pub fn with_cards_too(mut self, cards_too: bool) -> Self {
+        self.cards_too = Some(cards_too);
+        self
+    }
 }
 
 impl<'a> Request for DeleteDecks<'a> {
@@ -124,10 +133,133 @@ impl<'a> Request for DeleteDecks<'a> {
     fn get_params(&self) -> Option<&Self::Params> { Some(self) }
 }
 
-// TODO getDeckConfig
-// TODO saveDeckConfig
-// TODO cloneDeckConfigId
-// TODO removeDeckConfigId
+/// Corresponds to the `getDeckConfig` action.
+/// Returns the options preset used by the given deck, or `None` if the deck does not exist.
+#[derive(serde::Serialize)]
+pub struct GetDeckConfig<'a> {
+    /// Name of deck
+    deck: Cow<'a, str>,
+}
+
+impl<'a> GetDeckConfig<'a> {
+    pub fn new(deck: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            deck: deck.into(),
+        }
+    }
+}
+
+impl<'a> Request for GetDeckConfig<'a> {
+    type Params = Self;
+    type Response = FalseOr<DeckConfig>;
+    fn get_action(&self) -> &'static str { "getDeckConfig" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `saveDeckConfig` action.
+/// Saves the options preset with the config's ID. Returns `false` if that ID is invalid.
+#[derive(serde::Serialize)]
+pub struct SaveDeckConfig<'a> {
+    config: &'a DeckConfig,
+}
+
+impl<'a> SaveDeckConfig<'a> {
+    pub fn new(config: &'a DeckConfig) -> Self {
+        Self {
+            config,
+        }
+    }
+}
+
+impl<'a> Request for SaveDeckConfig<'a> {
+    type Params = Self;
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "saveDeckConfig" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `setDeckConfigId` action.
+/// Makes the given decks use the options preset with the given ID.
+/// Returns `false` if the ID is invalid or any of the decks do not exist.
+#[derive(serde::Serialize)]
+pub struct SetDeckConfigId<'a> {
+    /// Names of decks
+    decks: Vec<Cow<'a, str>>,
+    #[serde(rename = "configId")]
+    config_id: u64,
+}
+
+impl<'a> SetDeckConfigId<'a> {
+    pub fn new(decks: impl Into<Vec<Cow<'a, str>>>, config_id: u64) -> Self {
+        Self {
+            decks: decks.into(),
+            config_id,
+        }
+    }
+    pub fn single(deck: impl Into<Cow<'a, str>>, config_id: u64) -> Self {
+        Self::new([deck.into()], config_id)
+    }
+}
+
+impl<'a> Request for SetDeckConfigId<'a> {
+    type Params = Self;
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "setDeckConfigId" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `cloneDeckConfigId` action.
+/// Creates a new options preset with the given name, copied from the preset with ID
+/// `clone_from`, or from the default preset if no ID is given.
+/// Returns the ID of the new preset, or `None` if `clone_from` does not exist.
+#[derive(serde::Serialize)]
+pub struct CloneDeckConfigId<'a> {
+    name: Cow<'a, str>,
+    #[serde(
+        rename = "cloneFrom",
+        skip_serializing_if = "Option::is_none",
+    )]
+    clone_from: Option<u64>,
+}
+
+impl<'a> CloneDeckConfigId<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>, clone_from: impl Into<Option<u64>>) -> Self {
+        Self {
+            name: name.into(),
+            clone_from: clone_from.into(),
+        }
+    }
+}
+
+impl<'a> Request for CloneDeckConfigId<'a> {
+    type Params = Self;
+    type Response = FalseOr<u64>;
+    fn get_action(&self) -> &'static str { "cloneDeckConfigId" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `removeDeckConfigId` action.
+/// Removes the options preset with the given ID. Returns `false` if it does not exist.
+#[derive(serde::Serialize)]
+pub struct RemoveDeckConfigId {
+    #[serde(rename = "configId")]
+    config_id: u64,
+}
+
+impl RemoveDeckConfigId {
+    pub fn new(config_id: u64) -> Self {
+        Self {
+            config_id,
+        }
+    }
+}
+
+impl Request for RemoveDeckConfigId {
+    type Params = Self;
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "removeDeckConfigId" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
 
 /// Corresponds to the `getDeckStats` action
 #[derive(serde::Serialize)]
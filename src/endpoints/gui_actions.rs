@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use crate::endpoints::request::Request;
-use crate::models::{Order, BrowserColumn};
+use crate::models::{Order, BrowserColumn, CurrentCard, Ease, Note};
 
 #[derive(serde::Serialize)]
 pub struct GuiBrowseCardOrderOptions {
@@ -201,6 +201,14 @@ pub struct GuiSelectCard {
     card: u64,
 }
 
+impl GuiSelectCard {
+    pub fn new(card: u64) -> Self {
+        Self {
+            card,
+        }
+    }
+}
+
 impl Request for GuiSelectCard {
     type Params = Self;
     type Response = bool;
@@ -208,17 +216,196 @@ impl Request for GuiSelectCard {
     fn get_params(&self) -> Option<&Self::Params> { Some(self) }
 }
 
-// TODO guiSelectedNotes
-// TODO guiAddCards
-// TODO guiEditNote
-// TODO guiCurrentCard
-// TODO guiStartCardTimer
-// TODO guiShowQuestion
-// TODO guiShowAnswer
-// TODO guiAnswerCard
+/// Corresponds to the `guiSelectNote` action.
+/// Expects the Card Browser dialog to already be open.
+/// Returns `true` if the Card Browser dialog is open.
+#[derive(serde::Serialize)]
+pub struct GuiSelectNote {
+    /// Note ID
+    note: u64,
+}
+
+impl GuiSelectNote {
+    pub fn new(note: u64) -> Self {
+        Self {
+            note,
+        }
+    }
+}
+
+impl Request for GuiSelectNote {
+    type Params = Self;
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "guiSelectNote" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `guiSelectedNotes` action.
+/// Returns the IDs of the notes selected in the Card Browser, or an empty Vec if it is not open.
+#[derive(Default)]
+pub struct GuiSelectedNotes;
+
+impl Request for GuiSelectedNotes {
+    type Params = ();
+    type Response = Vec<u64>;
+    fn get_action(&self) -> &'static str { "guiSelectedNotes" }
+}
+
+/// Corresponds to the `guiAddCards` action.
+/// Opens the Add dialog, prefilled with the given note, so the user can review it before adding
+/// it. Returns the ID of the note in the dialog.
+#[derive(serde::Serialize)]
+pub struct GuiAddCards<'a> {
+    note: GuiAddCardsNote<'a>,
+}
+
+#[derive(serde::Serialize)]
+struct GuiAddCardsNote<'a> {
+    #[serde(flatten)]
+    note: Note<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<GuiAddCardsOptions>,
+}
+
+#[derive(serde::Serialize)]
+struct GuiAddCardsOptions {
+    #[serde(rename = "closeAfterAdding")]
+    close_after_adding: bool,
+}
+
+impl<'a> GuiAddCards<'a> {
+
+    pub fn new(note: Note<'a>) -> Self {
+        Self {
+            note: GuiAddCardsNote {
+                note,
+                options: None,
+            },
+        }
+    }
+
+    /// Close the Add dialog once the user adds the note.
+    pub fn with_close_after_adding(mut self, close_after_adding: bool) -> Self {
+        self.note.options = Some(GuiAddCardsOptions {
+            close_after_adding,
+        });
+        self
+    }
+
+}
+
+impl<'a> Request for GuiAddCards<'a> {
+    type Params = Self;
+    type Response = u64;
+    fn get_action(&self) -> &'static str { "guiAddCards" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `guiEditNote` action.
+/// Opens the Edit dialog for the given note.
+#[derive(serde::Serialize)]
+pub struct GuiEditNote {
+    /// Note ID
+    note: u64,
+}
+
+impl GuiEditNote {
+    pub fn new(note: u64) -> Self {
+        Self {
+            note,
+        }
+    }
+}
+
+impl Request for GuiEditNote {
+    type Params = Self;
+    type Response = ();
+    fn get_action(&self) -> &'static str { "guiEditNote" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `guiCurrentCard` action.
+/// Returns the card shown in the reviewer, or `None` if Anki is not reviewing.
+#[derive(Default)]
+pub struct GuiCurrentCard;
+
+impl Request for GuiCurrentCard {
+    type Params = ();
+    type Response = Option<CurrentCard>;
+    fn get_action(&self) -> &'static str { "guiCurrentCard" }
+}
+
+/// Corresponds to the `guiStartCardTimer` action.
+/// Starts or resets the answer timer of the current card.
+/// Returns `true` on success.
+#[derive(Default)]
+pub struct GuiStartCardTimer;
+
+impl Request for GuiStartCardTimer {
+    type Params = ();
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "guiStartCardTimer" }
+}
+
+/// Corresponds to the `guiShowQuestion` action.
+/// Shows the question of the current card. Returns `true` if Anki is reviewing.
+#[derive(Default)]
+pub struct GuiShowQuestion;
+
+impl Request for GuiShowQuestion {
+    type Params = ();
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "guiShowQuestion" }
+}
+
+/// Corresponds to the `guiShowAnswer` action.
+/// Shows the answer of the current card. Returns `true` if Anki is reviewing.
+#[derive(Default)]
+pub struct GuiShowAnswer;
+
+impl Request for GuiShowAnswer {
+    type Params = ();
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "guiShowAnswer" }
+}
+
+/// Corresponds to the `guiAnswerCard` action.
+/// Answers the current card. The answer must be shown first.
+/// Returns `true` on success.
+#[derive(serde::Serialize)]
+pub struct GuiAnswerCard {
+    ease: Ease,
+}
+
+impl GuiAnswerCard {
+    pub fn new(ease: Ease) -> Self {
+        Self {
+            ease,
+        }
+    }
+}
+
+impl Request for GuiAnswerCard {
+    type Params = Self;
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "guiAnswerCard" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `guiPlayAudio` action.
+/// Plays the audio of the current card's side. Returns `true` if Anki is reviewing.
+#[derive(Default)]
+pub struct GuiPlayAudio;
+
+impl Request for GuiPlayAudio {
+    type Params = ();
+    type Response = bool;
+    fn get_action(&self) -> &'static str { "guiPlayAudio" }
+}
 
 /// Corresponds to the `guiUndo` action.
 /// Returns `true` on success.
+#[derive(Default)]
 pub struct GuiUndo;
 
 impl Request for GuiUndo {
@@ -235,6 +422,14 @@ pub struct GuiDeckOverview<'a> {
     name: Cow<'a, str>,
 }
 
+impl<'a> GuiDeckOverview<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+        }
+    }
+}
+
 impl<'a> Request for GuiDeckOverview<'a> {
     type Params = Self;
     type Response = bool;
@@ -244,6 +439,7 @@ impl<'a> Request for GuiDeckOverview<'a> {
 
 /// Invokes the `guiDeckBrowser` action.
 /// Opens the Deck Browser dialog.
+#[derive(Default)]
 pub struct GuiDeckBrowser;
 
 impl Request for GuiDeckBrowser {
@@ -260,6 +456,14 @@ pub struct GuiDeckReview<'a> {
     name: Cow<'a, str>,
 }
 
+impl<'a> GuiDeckReview<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+        }
+    }
+}
+
 impl<'a> Request for GuiDeckReview<'a> {
     type Params = Self;
     type Response = bool;
@@ -269,11 +473,20 @@ impl<'a> Request for GuiDeckReview<'a> {
 
 /// Invokes the `guiImportFile` action.
 /// Opens the Import... dialog.
-#[derive(serde::Serialize)]
+/// Without a path, the user is asked to pick a file first.
+#[derive(Default, serde::Serialize)]
 pub struct GuiImportFile<'a> {
     path: Option<Cow<'a, str>>,
 }
 
+impl<'a> GuiImportFile<'a> {
+    pub fn new(path: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            path: Some(path.into()),
+        }
+    }
+}
+
 impl<'a> Request for GuiImportFile<'a> {
     type Params = Self;
     type Response = ();
@@ -289,6 +502,7 @@ impl<'a> Request for GuiImportFile<'a> {
 /// Invokes the `guiExitAnki` action.
 /// According to API docs, the API request will return immediately and does not wait for Anki to
 /// actually close.
+#[derive(Default)]
 pub struct GuiExitAnki;
 
 impl Request for GuiExitAnki {
@@ -299,10 +513,26 @@ impl Request for GuiExitAnki {
 
 /// Invokes the `guiCheckDatabase` action.
 /// According to API docs, it should always return `true`.
+#[derive(Default)]
 pub struct GuiCheckDatabase;
 
 impl Request for GuiCheckDatabase {
     type Params = ();
-    type Response = ();
+    type Response = bool;
     fn get_action(&self) -> &'static str { "guiCheckDatabase" }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ResponseBody;
+
+    // Anki-Connect replies `true`, which a `()` response can't be decoded from
+    #[test]
+    fn gui_check_database_response() {
+        let body = serde_json::json!({"result": true, "error": null});
+        let body = serde_json::from_value::<ResponseBody<<GuiCheckDatabase as Request>::Response>>(body).unwrap();
+        assert_eq!(body.into_result(), Ok(true));
+    }
+
+}
@@ -10,6 +10,7 @@ use crate::{
 #[derive(serde::Serialize)]
 pub struct StoreMediaFile<'a> {
     filename: Cow<'a, str>,
+    #[serde(flatten)]
     source: MediaSource<'a>,
 
     #[serde(
@@ -18,11 +19,37 @@ pub struct StoreMediaFile<'a> {
     )]
     delete_existing: Option<bool>,
 
+    /// Skip storing the file if its MD5 hash matches this value
     #[serde(
         rename = "skipHash",
         skip_serializing_if = "Option::is_none",
     )]
-    skip_hash: Option<bool>,
+    skip_hash: Option<Cow<'a, str>>,
+
+}
+
+impl<'a> StoreMediaFile<'a> {
+
+    pub fn new(filename: impl Into<Cow<'a, str>>, source: MediaSource<'a>) -> Self {
+        Self {
+            filename: filename.into(),
+            source,
+            delete_existing: None,
+            skip_hash: None,
+        }
+    }
+
+    /// Whether to replace an existing file with the same name.
+    /// If `false`, Anki-Connect stores the file under a new name instead.
+    pub fn with_delete_existing(mut self, delete_existing: bool) -> Self {
+        self.delete_existing = Some(delete_existing);
+        self
+    }
+
+    pub fn with_skip_hash(mut self, skip_hash: impl Into<Cow<'a, str>>) -> Self {
+        self.skip_hash = Some(skip_hash.into());
+        self
+    }
 
 }
 
@@ -39,6 +66,14 @@ pub struct RetrieveMediaFile<'a> {
     filename: Cow<'a, str>,
 }
 
+impl<'a> RetrieveMediaFile<'a> {
+    pub fn new(filename: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            filename: filename.into(),
+        }
+    }
+}
+
 impl<'a> Request for RetrieveMediaFile<'a> {
     type Response = String;
     type Params = Self;
@@ -108,3 +143,23 @@ impl<'a> Request for DeleteMediaFile<'a> {
     fn get_action(&self) -> &'static str { "deleteMediaFile" }
     fn get_params(&self) -> Option<&Self::Params> { Some(self) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Anki-Connect compares `skipHash` with the file's MD5 hash, so it is a string, not a flag
+    #[test]
+    fn serialize_skip_hash() {
+        let source = MediaSource::Url(Cow::Owned("https://example.com/a.mp3".to_string()));
+        let request = StoreMediaFile::new("a.mp3", source)
+            .with_skip_hash("d41d8cd98f00b204e9800998ecf8427e");
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value, serde_json::json!({
+            "filename": "a.mp3",
+            "url": "https://example.com/a.mp3",
+            "skipHash": "d41d8cd98f00b204e9800998ecf8427e",
+        }));
+    }
+
+}
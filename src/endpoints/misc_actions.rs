@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use crate::endpoints::request::Request;
 
 // Miscellaneous Actions
@@ -15,7 +16,55 @@ impl Request for Version {
     fn get_action(&self) -> &'static str { "version" }
 }
 
-// TODO apiReflect
+/// Corresponds to the `apiReflect` action.
+/// Returns which of the given actions the server supports, or every supported action if none
+/// are given.
+#[derive(serde::Serialize)]
+pub struct ApiReflect<'a> {
+    scopes: Vec<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actions: Option<Vec<Cow<'a, str>>>,
+}
+
+impl<'a> ApiReflect<'a> {
+
+    pub fn new(
+        scopes: impl Into<Vec<Cow<'a, str>>>,
+        actions: Option<Vec<Cow<'a, str>>>,
+    ) -> Self {
+        Self {
+            scopes: scopes.into(),
+            actions,
+        }
+    }
+
+    /// Ask which of `actions` are supported.
+    pub fn supported(actions: impl Into<Vec<Cow<'a, str>>>) -> Self {
+        Self::new(["actions".into()], Some(actions.into()))
+    }
+
+}
+
+impl<'a> Default for ApiReflect<'a> {
+    /// Ask for every supported action.
+    fn default() -> Self {
+        Self::new(["actions".into()], None)
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct ApiReflectResponse {
+    pub scopes: Vec<String>,
+    #[serde(default)]
+    pub actions: Vec<String>,
+}
+
+impl<'a> Request for ApiReflect<'a> {
+    type Response = ApiReflectResponse;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "apiReflect" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
 
 /// Corresponds to the `sync` action.
 /// Prompts the running Anki instance to sync with AnkiWeb.
@@ -39,6 +88,8 @@ impl Request for GetProfiles {
     fn get_action(&self) -> &'static str { "getProfiles" }
 }
 
+/// Corresponds to the `getActiveProfile` action.
+/// Returns the name of the profile that is currently loaded.
 #[derive(Default)]
 pub struct GetActiveProfile;
 
@@ -48,8 +99,102 @@ impl Request for GetActiveProfile {
     fn get_action(&self) -> &'static str { "getActiveProfile" }
 }
 
-// TODO loadProfile
+/// Corresponds to the `loadProfile` action.
+/// Switches to the given profile. Returns `true` on success.
+#[derive(serde::Serialize)]
+pub struct LoadProfile<'a> {
+    name: Cow<'a, str>,
+}
+
+impl<'a> LoadProfile<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+        }
+    }
+}
+
+impl<'a> Request for LoadProfile<'a> {
+    type Response = bool;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "loadProfile" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
 // TODO multi
-// TODO exportPackage
-// TODO importPackage
-// TODO reloadCollection
+
+/// Corresponds to the `exportPackage` action.
+/// Exports the deck to an `.apkg` file at `path`, which is relative to the Anki-Connect server.
+/// Returns `true` on success.
+#[derive(serde::Serialize)]
+pub struct ExportPackage<'a> {
+    deck: Cow<'a, str>,
+    path: Cow<'a, str>,
+    #[serde(
+        rename = "includeSched",
+        skip_serializing_if = "Option::is_none",
+    )]
+    include_sched: Option<bool>,
+}
+
+impl<'a> ExportPackage<'a> {
+
+    pub fn new(
+        deck: impl Into<Cow<'a, str>>,
+        path: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            deck: deck.into(),
+            path: path.into(),
+            include_sched: None,
+        }
+    }
+
+    /// Whether to include scheduling information (review history, due dates) in the package.
+    pub fn with_include_sched(mut self, include_sched: bool) -> Self {
+        self.include_sched = Some(include_sched);
+        self
+    }
+
+}
+
+impl<'a> Request for ExportPackage<'a> {
+    type Response = bool;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "exportPackage" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `importPackage` action.
+/// Imports the `.apkg` file at `path`, which is relative to the Anki-Connect server.
+/// Returns `true` on success.
+#[derive(serde::Serialize)]
+pub struct ImportPackage<'a> {
+    path: Cow<'a, str>,
+}
+
+impl<'a> ImportPackage<'a> {
+    pub fn new(path: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            path: path.into(),
+        }
+    }
+}
+
+impl<'a> Request for ImportPackage<'a> {
+    type Response = bool;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "importPackage" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `reloadCollection` action.
+/// Tells Anki to reload all data from the database.
+#[derive(Default)]
+pub struct ReloadCollection;
+
+impl Request for ReloadCollection {
+    type Response = ();
+    type Params = ();
+    fn get_action(&self) -> &'static str { "reloadCollection" }
+}
@@ -1,5 +1,8 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 use crate::endpoints::request::Request;
+use crate::error::Error;
+use crate::models::{CardTemplate, ModelInfo};
 
 #[derive(Default)]
 pub struct ModelNames;
@@ -19,14 +22,352 @@ impl Request for ModelNamesAndIds {
     fn get_action(&self) -> &'static str { "modelNamesAndIds" }
 }
 
-// TODO findModelsById
-// TODO findModelsByName
-// TODO modelFieldNames
-// TODO modelFieldDescriptions
-// TODO modelFieldFonts
-// TODO modelFieldsOnTemplates
-// TODO createModel
-// TODO modelTemplates
+/// Corresponds to the `findModelsById` action.
+#[derive(serde::Serialize)]
+pub struct FindModelsById<'a> {
+    #[serde(rename = "modelIds")]
+    model_ids: Cow<'a, [u64]>,
+}
+
+impl<'a> FindModelsById<'a> {
+    pub fn new(model_ids: impl Into<Cow<'a, [u64]>>) -> Self {
+        Self {
+            model_ids: model_ids.into(),
+        }
+    }
+}
+
+impl<'a> Request for FindModelsById<'a> {
+    type Response = Vec<ModelInfo>;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "findModelsById" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `findModelsByName` action.
+#[derive(serde::Serialize)]
+pub struct FindModelsByName<'a> {
+    #[serde(rename = "modelNames")]
+    model_names: Vec<Cow<'a, str>>,
+}
+
+impl<'a> FindModelsByName<'a> {
+    pub fn new(model_names: impl Into<Vec<Cow<'a, str>>>) -> Self {
+        Self {
+            model_names: model_names.into(),
+        }
+    }
+    pub fn single(model_name: impl Into<Cow<'a, str>>) -> Self {
+        Self::new([model_name.into()])
+    }
+}
+
+impl<'a> Request for FindModelsByName<'a> {
+    type Response = Vec<ModelInfo>;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "findModelsByName" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldNames` action.
+/// Returns the names of the model's fields, in order.
+#[derive(serde::Serialize)]
+pub struct ModelFieldNames<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+}
+
+impl<'a> ModelFieldNames<'a> {
+    pub fn new(model_name: impl Into<Cow<'a, str>>) -> Self {
+        ModelFieldNames {
+            model_name: model_name.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldNames<'a> {
+    type Response = Vec<String>;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldNames" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldDescriptions` action.
+/// Returns the description of each of the model's fields, in order.
+#[derive(serde::Serialize)]
+pub struct ModelFieldDescriptions<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+}
+
+impl<'a> ModelFieldDescriptions<'a> {
+    pub fn new(model_name: impl Into<Cow<'a, str>>) -> Self {
+        ModelFieldDescriptions {
+            model_name: model_name.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldDescriptions<'a> {
+    type Response = Vec<String>;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldDescriptions" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldFonts` action.
+/// Returns the editor font of each field, keyed by field name.
+#[derive(serde::Serialize)]
+pub struct ModelFieldFonts<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+}
+
+impl<'a> ModelFieldFonts<'a> {
+    pub fn new(model_name: impl Into<Cow<'a, str>>) -> Self {
+        ModelFieldFonts {
+            model_name: model_name.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FieldFont {
+    pub font: String,
+    pub size: u32,
+}
+
+impl<'a> Request for ModelFieldFonts<'a> {
+    type Response = HashMap<String, FieldFont>;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldFonts" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldsOnTemplates` action.
+/// Returns the fields used on the front and back of each template, keyed by template name.
+#[derive(serde::Serialize)]
+pub struct ModelFieldsOnTemplates<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+}
+
+impl<'a> ModelFieldsOnTemplates<'a> {
+    pub fn new(model_name: impl Into<Cow<'a, str>>) -> Self {
+        ModelFieldsOnTemplates {
+            model_name: model_name.into(),
+        }
+    }
+}
+
+/// Anki-Connect replies with a `[front, back]` pair for each template
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct FieldsOnTemplate {
+    pub front: Vec<String>,
+    pub back: Vec<String>,
+}
+
+impl<'a> Request for ModelFieldsOnTemplates<'a> {
+    type Response = HashMap<String, FieldsOnTemplate>;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldsOnTemplates" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `createModel` action.
+/// Returns the created model.
+/// Construct with `CreateModel::builder`, which checks the model for mistakes Anki would
+/// otherwise accept or report unhelpfully.
+#[derive(serde::Serialize)]
+pub struct CreateModel<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+
+    #[serde(rename = "inOrderFields")]
+    fields: Vec<Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    css: Option<Cow<'a, str>>,
+
+    #[serde(rename = "isCloze")]
+    is_cloze: bool,
+
+    #[serde(rename = "cardTemplates")]
+    templates: Vec<NamedCardTemplate<'a>>,
+}
+
+#[derive(serde::Serialize)]
+struct NamedCardTemplate<'a> {
+    #[serde(rename = "Name")]
+    name: Cow<'a, str>,
+    #[serde(flatten)]
+    template: CardTemplate,
+}
+
+impl<'a> CreateModel<'a> {
+    pub fn builder(model_name: impl Into<Cow<'a, str>>) -> CreateModelBuilder<'a> {
+        CreateModelBuilder::new(model_name)
+    }
+}
+
+impl<'a> Request for CreateModel<'a> {
+    type Response = ModelInfo;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "createModel" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Builds a `CreateModel` request.
+///
+/// ```
+/// use ankiconnect::endpoints::CreateModel;
+/// use ankiconnect::models::CardTemplate;
+///
+/// let request = CreateModel::builder("Vocab")
+///     .field("Word")
+///     .field("Meaning")
+///     .template("Recognition", CardTemplate::new("{{Word}}", "{{FrontSide}}<hr>{{Meaning}}"))
+///     .css(".card { font-size: 24px; }")
+///     .build()
+///     .unwrap();
+///
+/// let duplicate = CreateModel::builder("Vocab")
+///     .field("Word")
+///     .field("Word")
+///     .template("Recognition", CardTemplate::new("{{Word}}", "{{Word}}"))
+///     .build();
+/// assert!(duplicate.is_err());
+/// ```
+pub struct CreateModelBuilder<'a> {
+    model_name: Cow<'a, str>,
+    fields: Vec<Cow<'a, str>>,
+    css: Option<Cow<'a, str>>,
+    is_cloze: bool,
+    templates: Vec<NamedCardTemplate<'a>>,
+}
+
+impl<'a> CreateModelBuilder<'a> {
+
+    pub fn new(model_name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            model_name: model_name.into(),
+            fields: Vec::new(),
+            css: None,
+            is_cloze: false,
+            templates: Vec::new(),
+        }
+    }
+
+    /// Append a field. Fields keep the order they are added in.
+    pub fn field(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.fields.push(name.into());
+        self
+    }
+
+    /// Append a card template. Templates keep the order they are added in.
+    pub fn template(mut self, name: impl Into<Cow<'a, str>>, template: CardTemplate) -> Self {
+        self.templates.push(NamedCardTemplate {
+            name: name.into(),
+            template,
+        });
+        self
+    }
+
+    pub fn css(mut self, css: impl Into<Cow<'a, str>>) -> Self {
+        self.css = Some(css.into());
+        self
+    }
+
+    pub fn cloze(mut self, is_cloze: bool) -> Self {
+        self.is_cloze = is_cloze;
+        self
+    }
+
+    /// Fails if there are no fields or templates, if field or template names are repeated
+    /// (ignoring case, as Anki does), or if a cloze model has no `{{cloze:...}}` reference.
+    pub fn build(self) -> Result<CreateModel<'a>, Error> {
+
+        if self.fields.is_empty() {
+            return Err(Error::Invalid(format!("model {:?} has no fields", self.model_name)));
+        }
+
+        if self.templates.is_empty() {
+            return Err(Error::Invalid(format!("model {:?} has no templates", self.model_name)));
+        }
+
+        if let Some(x) = find_duplicate(self.fields.iter().map(|x| x.as_ref())) {
+            return Err(Error::Invalid(format!("duplicate field name {x:?}")));
+        }
+
+        if let Some(x) = find_duplicate(self.templates.iter().map(|x| x.name.as_ref())) {
+            return Err(Error::Invalid(format!("duplicate template name {x:?}")));
+        }
+
+        if self.is_cloze {
+            let has_cloze = self.templates
+                .iter()
+                .any(|x| references_cloze(&x.template.front) || references_cloze(&x.template.back));
+            if !has_cloze {
+                return Err(Error::Invalid(format!(
+                    "cloze model {:?} has no {{{{cloze:...}}}} reference in its templates",
+                    self.model_name,
+                )));
+            }
+        }
+
+        Ok(CreateModel {
+            model_name: self.model_name,
+            fields: self.fields,
+            css: self.css,
+            is_cloze: self.is_cloze,
+            templates: self.templates,
+        })
+    }
+
+}
+
+/// The first name that repeats an earlier one, ignoring case.
+pub(crate) fn find_duplicate<'b>(names: impl Iterator<Item = &'b str>) -> Option<&'b str> {
+    let mut seen = std::collections::HashSet::new();
+    names.into_iter().find(|x| !seen.insert(x.to_lowercase()))
+}
+
+/// `true` if any `{{...}}` tag in the template applies the `cloze` filter, e.g. `{{cloze:Text}}`
+/// or `{{type:cloze:Text}}`.
+pub(crate) fn references_cloze(template: &str) -> bool {
+    template
+        .split("{{")
+        .skip(1)
+        .filter_map(|x| x.split_once("}}").map(|(tag, _)| tag))
+        .any(|tag| {
+            let mut filters: Vec<&str> = tag.split(':').map(str::trim).collect();
+            filters.pop();
+            filters.contains(&"cloze")
+        })
+}
+
+/// Corresponds to the `modelTemplates` action.
+/// Returns the front and back of each template, keyed by template name.
+#[derive(serde::Serialize)]
+pub struct ModelTemplates<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+}
+
+impl<'a> ModelTemplates<'a> {
+    pub fn new(model_name: impl Into<Cow<'a, str>>) -> Self {
+        ModelTemplates {
+            model_name: model_name.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelTemplates<'a> {
+    type Response = HashMap<String, CardTemplate>;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelTemplates" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
 
 /// Corresponds to the `modelStyling` action
 #[derive(serde::Serialize)]
@@ -55,19 +396,627 @@ impl<'a> Request for ModelStyling<'a> {
     fn get_params(&self) -> Option<&Self::Params> { Some(self) }
 }
 
-// TODO updateModelTemplates
-// TODO updateModelStyling
-// TODO findAndReplaceInModels
-// TODO modelTemplateRename
-// TODO modelTemplateReposition
-// TODO modelTemplateAdd
-// TODO modelTemplateRemove
-// TODO modelFieldRename
-// TODO modelFieldSetFont
-// TODO modelFieldSetFontSize
-// TODO modelFieldSetDescription
-// TODO modelFieldAdd
-// TODO modelFieldRemove
-// TODO modelFieldSetFont
-// TODO modelFieldSetFontSize
-// TODO modelFieldSetDescription
+/// Corresponds to the `updateModelTemplates` action.
+/// Replaces the front and back of the named templates. Templates that are not given are left
+/// unchanged.
+#[derive(serde::Serialize)]
+pub struct UpdateModelTemplates<'a> {
+    model: UpdateModelTemplatesParams<'a>,
+}
+
+#[derive(serde::Serialize)]
+struct UpdateModelTemplatesParams<'a> {
+    name: Cow<'a, str>,
+    templates: HashMap<Cow<'a, str>, Cow<'a, CardTemplate>>,
+}
+
+impl<'a> UpdateModelTemplates<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        templates: HashMap<Cow<'a, str>, Cow<'a, CardTemplate>>,
+    ) -> Self {
+        Self {
+            model: UpdateModelTemplatesParams {
+                name: model_name.into(),
+                templates,
+            },
+        }
+    }
+    pub fn single(
+        model_name: impl Into<Cow<'a, str>>,
+        template_name: impl Into<Cow<'a, str>>,
+        template: impl Into<Cow<'a, CardTemplate>>,
+    ) -> Self {
+        Self::new(model_name, HashMap::from([(template_name.into(), template.into())]))
+    }
+}
+
+impl<'a> Request for UpdateModelTemplates<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "updateModelTemplates" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `updateModelStyling` action.
+/// Replaces the CSS of the model.
+#[derive(serde::Serialize)]
+pub struct UpdateModelStyling<'a> {
+    model: UpdateModelStylingParams<'a>,
+}
+
+#[derive(serde::Serialize)]
+struct UpdateModelStylingParams<'a> {
+    name: Cow<'a, str>,
+    css: Cow<'a, str>,
+}
+
+impl<'a> UpdateModelStyling<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        css: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            model: UpdateModelStylingParams {
+                name: model_name.into(),
+                css: css.into(),
+            },
+        }
+    }
+}
+
+impl<'a> Request for UpdateModelStyling<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "updateModelStyling" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `findAndReplaceInModels` action.
+/// Replaces text in the templates and CSS of a model, or of every model.
+/// By default the front, back and CSS are all searched.
+/// Returns the number of models that were changed.
+#[derive(serde::Serialize)]
+pub struct FindAndReplaceInModels<'a> {
+    model: FindAndReplaceInModelsParams<'a>,
+}
+
+#[derive(serde::Serialize)]
+struct FindAndReplaceInModelsParams<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+
+    #[serde(rename = "findText")]
+    find_text: Cow<'a, str>,
+
+    #[serde(rename = "replaceText")]
+    replace_text: Cow<'a, str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    front: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    back: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    css: Option<bool>,
+}
+
+impl<'a> FindAndReplaceInModels<'a> {
+
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        find_text: impl Into<Cow<'a, str>>,
+        replace_text: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            model: FindAndReplaceInModelsParams {
+                model_name: model_name.into(),
+                find_text: find_text.into(),
+                replace_text: replace_text.into(),
+                front: None,
+                back: None,
+                css: None,
+            },
+        }
+    }
+
+    /// Search every model instead of a single one.
+    pub fn all_models(
+        find_text: impl Into<Cow<'a, str>>,
+        replace_text: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self::new("", find_text, replace_text)
+    }
+
+    pub fn with_front(mut self, front: bool) -> Self {
+        self.model.front = Some(front);
+        self
+    }
+
+    pub fn with_back(mut self, back: bool) -> Self {
+        self.model.back = Some(back);
+        self
+    }
+
+    pub fn with_css(mut self, css: bool) -> Self {
+        self.model.css = Some(css);
+        self
+    }
+
+}
+
+impl<'a> Request for FindAndReplaceInModels<'a> {
+    type Response = u64;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "findAndReplaceInModels" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelTemplateRename` action.
+#[derive(serde::Serialize)]
+pub struct ModelTemplateRename<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "oldTemplateName")]
+    old_template_name: Cow<'a, str>,
+    #[serde(rename = "newTemplateName")]
+    new_template_name: Cow<'a, str>,
+}
+
+impl<'a> ModelTemplateRename<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        old_template_name: impl Into<Cow<'a, str>>,
+        new_template_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            old_template_name: old_template_name.into(),
+            new_template_name: new_template_name.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelTemplateRename<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelTemplateRename" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelTemplateReposition` action.
+/// Moves the template to `index`, starting at 0.
+#[derive(serde::Serialize)]
+pub struct ModelTemplateReposition<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "templateName")]
+    template_name: Cow<'a, str>,
+    index: usize,
+}
+
+impl<'a> ModelTemplateReposition<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        template_name: impl Into<Cow<'a, str>>,
+        index: usize,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            template_name: template_name.into(),
+            index,
+        }
+    }
+}
+
+impl<'a> Request for ModelTemplateReposition<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelTemplateReposition" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelTemplateAdd` action.
+/// Adds the template at the end of the model's templates.
+#[derive(serde::Serialize)]
+pub struct ModelTemplateAdd<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    template: NamedCardTemplate<'a>,
+}
+
+impl<'a> ModelTemplateAdd<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        template_name: impl Into<Cow<'a, str>>,
+        template: CardTemplate,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            template: NamedCardTemplate {
+                name: template_name.into(),
+                template,
+            },
+        }
+    }
+}
+
+impl<'a> Request for ModelTemplateAdd<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelTemplateAdd" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelTemplateRemove` action.
+/// Removing a template deletes every card generated from it.
+#[derive(serde::Serialize)]
+pub struct ModelTemplateRemove<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "templateName")]
+    template_name: Cow<'a, str>,
+}
+
+impl<'a> ModelTemplateRemove<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        template_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            template_name: template_name.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelTemplateRemove<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelTemplateRemove" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldRename` action.
+#[derive(serde::Serialize)]
+pub struct ModelFieldRename<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "oldFieldName")]
+    old_field_name: Cow<'a, str>,
+    #[serde(rename = "newFieldName")]
+    new_field_name: Cow<'a, str>,
+}
+
+impl<'a> ModelFieldRename<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        old_field_name: impl Into<Cow<'a, str>>,
+        new_field_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            old_field_name: old_field_name.into(),
+            new_field_name: new_field_name.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldRename<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldRename" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldReposition` action.
+/// Moves the field to `index`, starting at 0.
+#[derive(serde::Serialize)]
+pub struct ModelFieldReposition<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "fieldName")]
+    field_name: Cow<'a, str>,
+    index: usize,
+}
+
+impl<'a> ModelFieldReposition<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        field_name: impl Into<Cow<'a, str>>,
+        index: usize,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            field_name: field_name.into(),
+            index,
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldReposition<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldReposition" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldAdd` action.
+/// Adds the field at `index`, starting at 0, or at the end if no index is given.
+#[derive(serde::Serialize)]
+pub struct ModelFieldAdd<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "fieldName")]
+    field_name: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
+}
+
+impl<'a> ModelFieldAdd<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        field_name: impl Into<Cow<'a, str>>,
+        index: impl Into<Option<usize>>,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            field_name: field_name.into(),
+            index: index.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldAdd<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldAdd" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldRemove` action.
+/// The contents of the field are deleted from every note of the model.
+#[derive(serde::Serialize)]
+pub struct ModelFieldRemove<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "fieldName")]
+    field_name: Cow<'a, str>,
+}
+
+impl<'a> ModelFieldRemove<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        field_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            field_name: field_name.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldRemove<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldRemove" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldSetFont` action.
+/// Sets the font used for the field in the editor.
+#[derive(serde::Serialize)]
+pub struct ModelFieldSetFont<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "fieldName")]
+    field_name: Cow<'a, str>,
+    font: Cow<'a, str>,
+}
+
+impl<'a> ModelFieldSetFont<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        field_name: impl Into<Cow<'a, str>>,
+        font: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            field_name: field_name.into(),
+            font: font.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldSetFont<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldSetFont" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldSetFontSize` action.
+/// Sets the font size used for the field in the editor.
+#[derive(serde::Serialize)]
+pub struct ModelFieldSetFontSize<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "fieldName")]
+    field_name: Cow<'a, str>,
+    #[serde(rename = "fontSize")]
+    font_size: u32,
+}
+
+impl<'a> ModelFieldSetFontSize<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        field_name: impl Into<Cow<'a, str>>,
+        font_size: u32,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            field_name: field_name.into(),
+            font_size,
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldSetFontSize<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldSetFontSize" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+/// Corresponds to the `modelFieldSetDescription` action.
+/// Sets the placeholder text shown for the field in the editor.
+/// Returns `false` if the running Anki version does not support field descriptions.
+#[derive(serde::Serialize)]
+pub struct ModelFieldSetDescription<'a> {
+    #[serde(rename = "modelName")]
+    model_name: Cow<'a, str>,
+    #[serde(rename = "fieldName")]
+    field_name: Cow<'a, str>,
+    description: Cow<'a, str>,
+}
+
+impl<'a> ModelFieldSetDescription<'a> {
+    pub fn new(
+        model_name: impl Into<Cow<'a, str>>,
+        field_name: impl Into<Cow<'a, str>>,
+        description: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            model_name: model_name.into(),
+            field_name: field_name.into(),
+            description: description.into(),
+        }
+    }
+}
+
+impl<'a> Request for ModelFieldSetDescription<'a> {
+    type Response = bool;
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "modelFieldSetDescription" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cloze_references() {
+        assert!(references_cloze("{{cloze:Text}}"));
+        assert!(references_cloze("<div>{{ type:cloze:Text }}</div>"));
+        assert!(!references_cloze("{{Text}}"));
+        assert!(!references_cloze("{{cloze}}"));
+        assert!(!references_cloze("cloze:Text"));
+    }
+
+    #[test]
+    fn cloze_model_without_cloze_reference() {
+        let result = CreateModel::builder("Cloze")
+            .field("Text")
+            .template("Cloze", CardTemplate::new("{{Text}}", "{{Text}}"))
+            .cloze(true)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serialize_template_and_styling_updates() {
+        let template = CardTemplate::new("{{Front}}", "{{Back}}");
+
+        let request = UpdateModelTemplates::single("Custom", "Card 1", &template);
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "model": {"name": "Custom", "templates": {"Card 1": {"Front": "{{Front}}", "Back": "{{Back}}"}}},
+        }));
+
+        let request = UpdateModelStyling::new("Custom", ".card {}");
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "model": {"name": "Custom", "css": ".card {}"},
+        }));
+
+        let request = FindAndReplaceInModels::new("Custom", "old", "new").with_front(true).with_css(false);
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "model": {"modelName": "Custom", "findText": "old", "replaceText": "new", "front": true, "css": false},
+        }));
+
+        let request = FindAndReplaceInModels::all_models("old", "new");
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "model": {"modelName": "", "findText": "old", "replaceText": "new"},
+        }));
+    }
+
+    #[test]
+    fn serialize_template_edits() {
+        let request = ModelTemplateRename::new("Custom", "Card 1", "Forward");
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "oldTemplateName": "Card 1", "newTemplateName": "Forward",
+        }));
+
+        let request = ModelTemplateReposition::new("Custom", "Card 1", 1);
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "templateName": "Card 1", "index": 1,
+        }));
+
+        let request = ModelTemplateAdd::new("Custom", "Card 2", CardTemplate::new("{{Back}}", "{{Front}}"));
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "template": {"Name": "Card 2", "Front": "{{Back}}", "Back": "{{Front}}"},
+        }));
+
+        let request = ModelTemplateRemove::new("Custom", "Card 2");
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "templateName": "Card 2",
+        }));
+    }
+
+    #[test]
+    fn serialize_field_edits() {
+        let request = ModelFieldRename::new("Custom", "Front", "Question");
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "oldFieldName": "Front", "newFieldName": "Question",
+        }));
+
+        let request = ModelFieldReposition::new("Custom", "Back", 0);
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "fieldName": "Back", "index": 0,
+        }));
+
+        let request = ModelFieldAdd::new("Custom", "Notes", 2);
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "fieldName": "Notes", "index": 2,
+        }));
+
+        let request = ModelFieldAdd::new("Custom", "Notes", None);
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "fieldName": "Notes",
+        }));
+
+        let request = ModelFieldRemove::new("Custom", "Notes");
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "fieldName": "Notes",
+        }));
+    }
+
+    #[test]
+    fn serialize_field_settings() {
+        let request = ModelFieldSetFont::new("Custom", "Front", "Courier");
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "fieldName": "Front", "font": "Courier",
+        }));
+
+        let request = ModelFieldSetFontSize::new("Custom", "Front", 20);
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "fieldName": "Front", "fontSize": 20,
+        }));
+
+        let request = ModelFieldSetDescription::new("Custom", "Front", "The question");
+        assert_eq!(serde_json::to_value(&request).unwrap(), serde_json::json!({
+            "modelName": "Custom", "fieldName": "Front", "description": "The question",
+        }));
+    }
+
+}
@@ -50,6 +50,18 @@ pub struct RemoveTags<'a> {
     tags: Cow<'a, str>,
 }
 
+impl<'a> RemoveTags<'a> {
+    pub fn new(
+        notes: impl Into<Cow<'a, [u64]>>,
+        tags: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            notes: notes.into(),
+            tags: tags.into(),
+        }
+    }
+}
+
 impl<'a> Request for RemoveTags<'a> {
     type Response = ();
     type Params = Self;
@@ -70,8 +82,16 @@ pub struct FindNotes<'a> {
     query: Cow<'a, str>,
 }
 
+impl<'a> FindNotes<'a> {
+    pub fn new(query: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            query: query.into(),
+        }
+    }
+}
+
 impl<'a> Request for FindNotes<'a> {
-    type Response = ();
+    type Response = Vec<u64>;
     type Params = Self;
     fn get_action(&self) -> &'static str { "findNotes" }
     fn get_params(&self) -> Option<&Self::Params> { Some(self) }
@@ -81,3 +101,18 @@ impl<'a> Request for FindNotes<'a> {
 // TODO notesModTime
 // TODO deleteNotes
 // TODO removeEmptyNotes
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ResponseBody;
+
+    // Anki-Connect replies with the IDs of the matching notes, which a `()` response discarded
+    #[test]
+    fn find_notes_response() {
+        let body = serde_json::json!({"result": [1483959289817_u64, 1483959291695_u64], "error": null});
+        let body = serde_json::from_value::<ResponseBody<<FindNotes as Request>::Response>>(body).unwrap();
+        assert_eq!(body.into_result(), Ok(vec![1483959289817, 1483959291695]));
+    }
+
+}
@@ -1,9 +1,11 @@
 use std::borrow::Cow;
 use crate::endpoints::request::Request;
+use crate::error::Error;
 use crate::models::card_review::{
     CardReviewTuple,
     CardReviewWithoutCardId,
 };
+use crate::models::{Rating, ReviewKind};
 
 /// Corresponds to the `getNumCardsReviewedToday` action.
 #[derive(Default)]
@@ -17,10 +19,15 @@ impl Request for GetNumCardsReviewedToday {
 }
 
 /// Corresponds to the `getNumCardsReviewedByDay` action
+/// With the `chrono` feature, days are returned as dates. Without it, they are `YYYY-MM-DD`
+/// strings; see [`crate::models::DailyCounts::from_reported`].
 #[derive(Default)]
 pub struct GetNumCardsReviewedByDay;
 
 impl Request for GetNumCardsReviewedByDay {
+    #[cfg(feature = "chrono")]
+    type Response = Vec<(chrono::NaiveDate, u64)>;
+    #[cfg(not(feature = "chrono"))]
     type Response = Vec<(String, u64)>;
     type Params = ();
     fn get_action(&self) -> &'static str { "getNumCardsReviewedByDay" }
@@ -28,6 +35,7 @@ impl Request for GetNumCardsReviewedByDay {
 }
 
 /// Corresponds to the `getCollectionStatsHTML` action
+/// The main figures can be pulled out of the report with [`crate::models::CollectionStats::parse`].
 #[derive(serde::Serialize)]
 pub struct GetCollectionStatsHtml {
     #[serde(rename = "wholeCollection")]
@@ -130,6 +138,10 @@ impl<'a> Request for GetLatestReviewId<'a> {
     fn get_params(&self) -> Option<&Self::Params> { Some(self) }
 }
 
+/// Corresponds to the `insertReviews` action.
+/// Anki-Connect does not check the reviews it is given. [`InsertReviews::validate`] checks what can
+/// be checked locally; [`crate::operations::insert_reviews`] additionally checks that the cards
+/// exist and that the review IDs are not taken before sending.
 #[derive(serde::Serialize)]
 pub struct InsertReviews<'a> {
     reviews: Cow<'a, [CardReviewTuple]>,
@@ -160,3 +172,92 @@ impl<'a> Default for InsertReviews<'a> {
         Self::new_from(&[])
     }
 }
+
+impl<'a> InsertReviews<'a> {
+
+    pub fn reviews(&self) -> &[CardReviewTuple] {
+        &self.reviews
+    }
+
+    /// Checks that review IDs are unique, card IDs are positive, and that each review's ease and
+    /// type are values Anki understands.
+    /// Ease 0 is only accepted for manual and rescheduled entries, which Anki records without an
+    /// answer.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut ids = std::collections::HashSet::new();
+        for review in self.reviews.iter() {
+            let (id, card_id, _, ease, _, _, _, _, review_type) = *review;
+            if !ids.insert(id) {
+                return Err(Error::Invalid(format!("review ID {id} occurs more than once")));
+            }
+            if card_id <= 0 {
+                return Err(Error::Invalid(format!("review {id} has invalid card ID {card_id}")));
+            }
+            let kind = ReviewKind::try_from(review_type)
+                .map_err(|x| Error::Invalid(format!("review {id} has invalid type {x}")))?;
+            let valid = match Rating::try_from(ease) {
+                Ok(Rating::Manual) => kind.is_manual(),
+                Ok(_) => true,
+                Err(_) => false,
+            };
+            if !valid {
+                return Err(Error::Invalid(format!("review {id} has invalid ease {ease}")));
+            }
+        }
+        Ok(())
+    }
+
+}
+
+impl<'a> Request for InsertReviews<'a> {
+    type Response = ();
+    type Params = Self;
+    fn get_action(&self) -> &'static str { "insertReviews" }
+    fn get_params(&self) -> Option<&Self::Params> { Some(self) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review(id: i64, ease: u8, review_type: u8) -> CardReviewTuple {
+        (id, 1, -1, ease, 1, -60, 2500, 6000, review_type)
+    }
+
+    #[test]
+    fn serialize_insert_reviews() {
+        let reviews = [review(1, 3, 1)];
+        let request = InsertReviews::new(Cow::Borrowed(&reviews));
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value, serde_json::json!({"reviews": [[1, 1, -1, 3, 1, -60, 2500, 6000, 1]]}));
+    }
+
+    #[test]
+    fn validate_insert_reviews() {
+        let valid = [review(1, 3, 1), review(2, 0, 4), review(3, 4, 0)];
+        assert!(InsertReviews::new(Cow::Borrowed(&valid)).validate().is_ok());
+
+        for invalid in [
+            vec![review(1, 3, 1), review(1, 2, 1)],
+            vec![(1, 0, -1, 3, 1, -60, 2500, 6000, 1)],
+            vec![review(1, 5, 1)],
+            vec![review(1, 0, 1)],
+            vec![review(1, 3, 6)],
+        ] {
+            let request = InsertReviews::new(Cow::Owned(invalid));
+            assert!(matches!(request.validate(), Err(Error::Invalid(_))));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn deserialize_reviewed_by_day() {
+        let value = serde_json::json!([["2024-01-29", 2], ["2024-02-02", 4]]);
+        let days: <GetNumCardsReviewedByDay as Request>::Response = serde_json::from_value(value).unwrap();
+        assert_eq!(days, vec![
+            (chrono::NaiveDate::from_ymd_opt(2024, 1, 29).unwrap(), 2),
+            (chrono::NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(), 4),
+        ]);
+    }
+
+}
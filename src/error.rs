@@ -10,9 +10,22 @@ pub enum Error {
 
     DeserializeReqwest(reqwest::Error),
 
+    /// Occurs if a value can't be serialized to JSON, e.g. when writing local files
+    SerializeSerde(serde_json::Error),
+
     /// When the request is otherwise OK and everything is valid, but the API operation did not
     /// complete successfully
     Normal(String),
+
+    /// Occurs when a request or value is rejected client-side, before anything is sent to the API
+    Invalid(String),
+
+    /// Occurs when an operation made of several requests finds Anki in an unexpected state part
+    /// way through, and stops rather than risk losing data
+    Operation(String),
+
+    /// Occurs when reading or writing local files fails
+    Io(std::io::Error),
 }
 
 impl std::fmt::Display for Error {
@@ -27,9 +40,21 @@ impl std::fmt::Display for Error {
             Error::DeserializeReqwest(e) => {
                 write!(f, "error deserializing response: {e}")
             }
+            Error::SerializeSerde(e) => {
+                write!(f, "error serializing: {e}")
+            }
             Error::Normal(x) => {
                 write!(f, "got error response: {x}")
             }
+            Error::Invalid(x) => {
+                write!(f, "invalid input: {x}")
+            }
+            Error::Operation(x) => {
+                write!(f, "operation stopped: {x}")
+            }
+            Error::Io(e) => {
+                write!(f, "i/o error: {e}")
+            }
         }
     }
 }
@@ -4,6 +4,7 @@ pub mod error;
 pub mod client;
 pub mod models;
 pub mod endpoints;
+pub mod operations;
 
 pub use client::Client;
 pub use error::Error;
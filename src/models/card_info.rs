@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::models::Interval;
+use crate::models::current_card::CurrentCardField;
+
+/// A card, as returned by `cardsInfo`.
+/// Keys that are not typed here are kept in `other`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct CardInfo {
+
+    #[serde(rename = "cardId")]
+    pub card_id: u64,
+
+    /// Note ID
+    pub note: u64,
+
+    #[serde(rename = "deckName")]
+    pub deck_name: String,
+
+    #[serde(rename = "modelName")]
+    pub model_name: String,
+
+    /// Rendered question (front) HTML
+    pub question: String,
+
+    /// Rendered answer (back) HTML
+    pub answer: String,
+
+    /// Field contents, keyed by field name
+    pub fields: HashMap<String, CurrentCardField>,
+
+    /// Index of the card's template
+    pub ord: usize,
+
+    pub interval: Interval,
+
+    /// Ease factor, in permille
+    pub factor: u32,
+
+    /// Meaning depends on `queue`: a position for new cards, a day number for reviews, or a
+    /// timestamp for cards in learning
+    pub due: i64,
+
+    pub reps: u32,
+
+    pub lapses: u32,
+
+    /// Backend type: `CardType`. 0 = new, 1 = learning, 2 = review, 3 = relearning
+    #[serde(rename = "type")]
+    pub kind: i8,
+
+    /// Backend type: `CardQueue`. Negative values mean suspended or buried
+    pub queue: i8,
+
+    /// Modification time, in seconds from UNIX epoch
+    #[serde(rename = "mod")]
+    pub modified: i64,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
@@ -1,8 +1,11 @@
+use crate::models::{Rating, ReviewKind, ReviewTimestamp};
+
 /// Represents a single review, or revlog entry.
 /// Field names are based on the documentation for the `insertReviews` endpoint, which may differ
 /// from how the fields are named in Anki's database or in Anki's backend code.
 /// The fields in this struct should correspond to the fields in `RevlogEntry`.
 /// https://github.com/ankitects/anki/blob/main/rslib/src/revlog/mod.rs
+#[derive(Clone, Debug, PartialEq)]
 pub struct CardReview {
 
     /// The review's ID is also the time it occurred in milliseconds from UNIX epoch
@@ -33,7 +36,7 @@ pub struct CardReview {
     pub review_type: u8,
 }
 
-#[derive(serde::Deserialize)]
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
 pub struct CardReviewWithoutCardId {
 
     pub id: i64,
@@ -106,6 +109,20 @@ impl CardReview {
             self.review_type,
         )
     }
+    /// Fails with the raw value if it is not a rating Anki knows.
+    pub fn rating(&self) -> Result<Rating, u8> {
+        Rating::try_from(self.ease)
+    }
+
+    /// Fails with the raw value if it is not a review kind Anki knows.
+    pub fn kind(&self) -> Result<ReviewKind, u8> {
+        ReviewKind::try_from(self.review_type)
+    }
+
+    pub fn timestamp(&self) -> ReviewTimestamp {
+        ReviewTimestamp(self.id)
+    }
+
     pub fn from_tuple(value: &CardReviewTuple) -> Self {
         Self {
             id: value.0,
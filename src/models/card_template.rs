@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+/// The front and back of a card template, as used by `modelTemplates`, `createModel` and
+/// `updateModelTemplates`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CardTemplate {
+    #[serde(rename = "Front")]
+    pub front: String,
+    #[serde(rename = "Back")]
+    pub back: String,
+}
+
+impl CardTemplate {
+    pub fn new(front: impl Into<String>, back: impl Into<String>) -> Self {
+        Self {
+            front: front.into(),
+            back: back.into(),
+        }
+    }
+}
+
+impl<'a> From<CardTemplate> for Cow<'a, CardTemplate> {
+    fn from(value: CardTemplate) -> Self {
+        Cow::Owned(value)
+    }
+}
+
+impl<'a> From<&'a CardTemplate> for Cow<'a, CardTemplate> {
+    fn from(value: &'a CardTemplate) -> Self {
+        Cow::Borrowed(value)
+    }
+}
@@ -0,0 +1,423 @@
+use std::time::Duration;
+
+/// The main figures of the report returned by `getCollectionStatsHTML`.
+///
+/// The report is HTML meant for display, so [`CollectionStats::parse`] looks for each figure by
+/// its section title and English label. Figures it can't find are left as `None` and named in
+/// `missing`, e.g. `"future_due.due_tomorrow"`, rather than failing the whole parse.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CollectionStats {
+    pub today: TodayStats,
+    pub future_due: FutureDueStats,
+    pub review_count: ReviewCountStats,
+    pub card_counts: CardCountStats,
+    pub intervals: IntervalStats,
+    pub ease: EaseStats,
+    pub answer_buttons: AnswerButtonStats,
+    /// Figures that could not be found
+    pub missing: Vec<&'static str>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TodayStats {
+    pub cards_studied: Option<u64>,
+    pub time_studied: Option<Duration>,
+    pub again_count: Option<u64>,
+    pub learn: Option<u64>,
+    pub review: Option<u64>,
+    pub relearn: Option<u64>,
+    pub filtered: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FutureDueStats {
+    pub total: Option<u64>,
+    pub average_per_day: Option<f64>,
+    pub due_tomorrow: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReviewCountStats {
+    pub days_studied: Option<u64>,
+    /// The number of days the report covers
+    pub days_in_period: Option<u64>,
+    pub total: Option<u64>,
+    pub average_for_days_studied: Option<f64>,
+    pub average_every_day: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CardCountStats {
+    pub total_cards: Option<u64>,
+    pub total_notes: Option<u64>,
+    pub mature: Option<u64>,
+    pub young_and_learning: Option<u64>,
+    pub unseen: Option<u64>,
+    /// Suspended and buried cards
+    pub suspended: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IntervalStats {
+    /// In days. Anki counts a month as 30 days and a year as 365
+    pub average_days: Option<f64>,
+    /// In days
+    pub longest_days: Option<f64>,
+}
+
+/// Ease factors, in percent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EaseStats {
+    pub lowest: Option<f64>,
+    pub average: Option<f64>,
+    pub highest: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnswerButtonStats {
+    pub learning: Option<CorrectAnswers>,
+    pub young: Option<CorrectAnswers>,
+    pub mature: Option<CorrectAnswers>,
+}
+
+/// Reviews not answered "Again".
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CorrectAnswers {
+    pub correct: u64,
+    pub total: u64,
+}
+
+impl CollectionStats {
+
+    pub fn parse(html: &str) -> Self {
+        let sections = split_sections(html);
+        let section = |titles: &[&str]| -> &str {
+            sections
+                .iter()
+                .find(|(title, _)| titles.iter().any(|x| title.eq_ignore_ascii_case(x)))
+                .map_or("", |(_, text)| text.as_str())
+        };
+        let today = section(&["Today"]);
+        let studied = after(today, "Studied ");
+        let nothing_studied = today.contains("No cards have been studied today");
+        let zero_if_nothing_studied = |x: Option<u64>| x.or(nothing_studied.then_some(0));
+        let today = TodayStats {
+            cards_studied: zero_if_nothing_studied(studied.and_then(number).map(|x| x as u64)),
+            time_studied: studied
+                .and_then(|x| x.split_once(" in ").map(|(_, x)| x))
+                .and_then(|x| x.split_once(" today").map(|(x, _)| x))
+                .and_then(time_span)
+                .map(Duration::from_secs_f64)
+                .or(nothing_studied.then_some(Duration::ZERO)),
+            again_count: zero_if_nothing_studied(count(today, "Again count:")),
+            learn: zero_if_nothing_studied(count(today, "Learn:")),
+            review: zero_if_nothing_studied(count(today, "Review:")),
+            relearn: zero_if_nothing_studied(count(today, "Relearn:")),
+            filtered: zero_if_nothing_studied(count(today, "Filtered:")),
+        };
+
+        let due = section(&["Future Due", "Forecast"]);
+        let future_due = FutureDueStats {
+            total: count(due, "Total:"),
+            average_per_day: value(due, "Average:"),
+            due_tomorrow: count(due, "Due tomorrow:"),
+        };
+
+        let reviews = section(&["Review Count", "Reviews"]);
+        // e.g. `81% (25 of 31)`
+        let days = after(reviews, "Days studied:").and_then(x_of_y);
+        let review_count = ReviewCountStats {
+            days_studied: days.map(|(x, _)| x),
+            days_in_period: days.map(|(_, x)| x),
+            total: count(reviews, "Total:"),
+            average_for_days_studied: value(reviews, "Average for days studied:"),
+            average_every_day: value(reviews, "If you studied every day:"),
+        };
+
+        let cards = section(&["Card Types", "Card Counts"]);
+        let card_counts = CardCountStats {
+            total_cards: count(cards, "Total cards:"),
+            total_notes: count(cards, "Total notes:"),
+            mature: count(cards, "Mature:"),
+            young_and_learning: count(cards, "Young+Learn:"),
+            unseen: count(cards, "Unseen:"),
+            suspended: count(cards, "Suspended+Buried:").or_else(|| count(cards, "Suspended:")),
+        };
+
+        let intervals = section(&["Intervals"]);
+        let intervals = IntervalStats {
+            average_days: after(intervals, "Average interval:").and_then(time_span).map(|x| x / 86_400.0),
+            longest_days: after(intervals, "Longest interval:").and_then(time_span).map(|x| x / 86_400.0),
+        };
+
+        let ease = EaseStats {
+            lowest: value(cards, "Lowest ease:"),
+            average: value(cards, "Average ease:"),
+            highest: value(cards, "Highest ease:"),
+        };
+
+        let buttons: Vec<CorrectAnswers> = section(&["Answer Buttons"])
+            .split("Correct:")
+            .skip(1)
+            .filter_map(correct_answers)
+            .collect();
+        let answer_buttons = AnswerButtonStats {
+            learning: buttons.first().copied(),
+            young: buttons.get(1).copied(),
+            mature: buttons.get(2).copied(),
+        };
+
+        let checks = [
+            ("today.cards_studied", today.cards_studied.is_some()),
+            ("today.time_studied", today.time_studied.is_some()),
+            ("today.again_count", today.again_count.is_some()),
+            ("today.learn", today.learn.is_some()),
+            ("today.review", today.review.is_some()),
+            ("today.relearn", today.relearn.is_some()),
+            ("today.filtered", today.filtered.is_some()),
+            ("future_due.total", future_due.total.is_some()),
+            ("future_due.average_per_day", future_due.average_per_day.is_some()),
+            ("future_due.due_tomorrow", future_due.due_tomorrow.is_some()),
+            ("review_count.days_studied", review_count.days_studied.is_some()),
+            ("review_count.days_in_period", review_count.days_in_period.is_some()),
+            ("review_count.total", review_count.total.is_some()),
+            ("review_count.average_for_days_studied", review_count.average_for_days_studied.is_some()),
+            ("review_count.average_every_day", review_count.average_every_day.is_some()),
+            ("card_counts.total_cards", card_counts.total_cards.is_some()),
+            ("card_counts.total_notes", card_counts.total_notes.is_some()),
+            ("card_counts.mature", card_counts.mature.is_some()),
+            ("card_counts.young_and_learning", card_counts.young_and_learning.is_some()),
+            ("card_counts.unseen", card_counts.unseen.is_some()),
+            ("card_counts.suspended", card_counts.suspended.is_some()),
+            ("intervals.average_days", intervals.average_days.is_some()),
+            ("intervals.longest_days", intervals.longest_days.is_some()),
+            ("ease.lowest", ease.lowest.is_some()),
+            ("ease.average", ease.average.is_some()),
+            ("ease.highest", ease.highest.is_some()),
+            ("answer_buttons.learning", answer_buttons.learning.is_some()),
+            ("answer_buttons.young", answer_buttons.young.is_some()),
+            ("answer_buttons.mature", answer_buttons.mature.is_some()),
+        ];
+        let missing = checks
+            .iter()
+            .filter(|(_, found)| !found)
+            .map(|(name, _)| *name)
+            .collect();
+
+        CollectionStats {
+            today,
+            future_due,
+            review_count,
+            card_counts,
+            intervals,
+            ease,
+            answer_buttons,
+            missing,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+}
+
+/// Splits the report into (title, text) pairs, one per `<h1>` heading.
+fn split_sections(html: &str) -> Vec<(String, String)> {
+    let lower = html.to_ascii_lowercase();
+    let mut starts: Vec<usize> = lower.match_indices("<h1").map(|(x, _)| x).collect();
+    starts.push(html.len());
+
+    starts
+        .windows(2)
+        .filter_map(|x| {
+            let section = &html[x[0]..x[1]];
+            let end = section.to_ascii_lowercase().find("</h1>")?;
+            let title = to_text(&section[..end]).trim().to_string();
+            Some((title, to_text(&section[end..])))
+        })
+        .collect()
+}
+
+/// Removes tags, putting line breaks where rows and paragraphs end, and decodes common entities.
+fn to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim_start_matches('/').to_ascii_lowercase();
+        let name = tag.split(|x: char| x.is_whitespace() || x == '/').next().unwrap_or("");
+        match name {
+            "br" | "tr" | "p" | "div" | "h1" | "h2" | "h3" | "li" | "table" | "center" => text.push('\n'),
+            "td" | "th" => text.push(' '),
+            _ => {},
+        }
+        rest = &rest[start + end + 1..];
+    }
+    text.push_str(rest);
+
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#x27;", "'")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The rest of the line after `label`.
+fn after<'a>(text: &'a str, label: &str) -> Option<&'a str> {
+    let start = text.find(label)? + label.len();
+    let rest = &text[start..];
+    Some(rest.split('\n').next().unwrap_or(rest))
+}
+
+fn value(text: &str, label: &str) -> Option<f64> {
+    after(text, label).and_then(number)
+}
+
+fn count(text: &str, label: &str) -> Option<u64> {
+    value(text, label).filter(|x| x.fract() == 0.0).map(|x| x as u64)
+}
+
+/// The first number in `text`, ignoring thousands separators.
+fn number(text: &str) -> Option<f64> {
+    let start = text.find(|x: char| x.is_ascii_digit())?;
+    let digits: String = text[start..]
+        .chars()
+        .take_while(|x| x.is_ascii_digit() || *x == '.' || *x == ',')
+        .filter(|x| *x != ',')
+        .collect();
+    digits.trim_end_matches('.').parse().ok()
+}
+
+/// A span like `3.5 minutes` or `1.2 months`, in seconds.
+fn time_span(text: &str) -> Option<f64> {
+    let amount = number(text)?;
+    let start = text.find(|x: char| x.is_ascii_digit())?;
+    let unit = text[start..]
+        .trim_start_matches(|x: char| x.is_ascii_digit() || x == '.' || x == ',')
+        .trim_start()
+        .to_ascii_lowercase();
+    let seconds = [
+        ("second", 1.0),
+        ("minute", 60.0),
+        ("hour", 3600.0),
+        ("day", 86_400.0),
+        ("month", 30.0 * 86_400.0),
+        ("year", 365.0 * 86_400.0),
+    ];
+    seconds
+        .iter()
+        .find(|(name, _)| unit.starts_with(name))
+        .map(|(_, x)| amount * x)
+}
+
+/// The two numbers of `(8 of 10)`, ignoring anything before the parenthesis.
+fn x_of_y(text: &str) -> Option<(u64, u64)> {
+    let (_, counts) = text.split_once('(')?;
+    let (x, y) = counts.split_once(" of ")?;
+    Some((number(x)? as u64, number(y)? as u64))
+}
+
+/// Parses `80.00% (8 of 10)`.
+fn correct_answers(text: &str) -> Option<CorrectAnswers> {
+    let (correct, total) = x_of_y(text)?;
+    Some(CorrectAnswers { correct, total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Shaped after the legacy report from Anki's `stats.py`, with the graphs shortened
+    const REPORT: &str = r##"<center><style>
+h1 { margin-bottom: 0; margin-top: 1em; }
+.pielabel { text-align:center; padding:0px; color:white; }
+</style>
+<div class=section><h1>Today</h1>Studied <b><!--studied-->1024 cards</b> <b>in 1.5 hours</b> today (5.3s/card)<br>Again count: <b>100</b> (<b>90.2%</b> correct)<br>Learn: <b>200</b>, Review: <b>800</b>, Relearn: <b>24</b>, Filtered: <b>0</b><br>Correct answers on mature cards: 700/750 (93.3%)</div>
+<div class=section><h1>Forecast</h1>The number of reviews due in the future.<table cellpadding=0 cellspacing=10><tr><td><div id="due" style="width:600px; height:200px;"></div></td></tr></table>
+<script>$(function () { $.plot($("#due"), [{"data": [[1, 320]], "label": "Young"}], {}); });</script>
+<table width=400><tr><td width=200 align=right>Total:</td><td><b>5000 reviews</b></td></tr><tr><td width=200 align=right>Average:</td><td><b>166.7 reviews/day</b></td></tr><tr><td width=200 align=right>Due tomorrow:</td><td><b>320 reviews</b></td></tr></table></div>
+<div class=section><h1>Review Count</h1>The number of questions you have answered.<table cellpadding=0 cellspacing=10><tr><td><div id="reps" style="width:600px; height:200px;"></div></td></tr></table>
+<script>$(function () { $.plot($("#reps"), [{"data": [[-1, 400]], "label": "Mature"}], {}); });</script>
+<table width=400><tr><td width=200 align=right>Days studied:</td><td><b>81%</b> (25 of 31)</td></tr><tr><td width=200 align=right>Total:</td><td><b>12345 reviews</b></td></tr><tr><td width=200 align=right>Average for days studied:</td><td><b>493.8 reviews/day</b></td></tr><tr><td width=200 align=right>If you studied every day:</td><td><b>398.2 reviews/day</b></td></tr></table></div>
+<div class=section><h1>Review Time</h1>The time taken to answer the questions.<table width=400><tr><td width=200 align=right>Days studied:</td><td><b>81%</b> (25 of 31)</td></tr><tr><td width=200 align=right>Total:</td><td><b>1234 minutes</b></td></tr><tr><td width=200 align=right>Average for days studied:</td><td><b>49.4 minutes/day</b></td></tr></table></div>
+<div class=section><h1>Added</h1>The number of new cards you have added.<table width=400><tr><td width=200 align=right>Total:</td><td><b>310 cards</b></td></tr><tr><td width=200 align=right>Average:</td><td><b>10.0 cards/day</b></td></tr></table></div>
+<div class=section><h1>Intervals</h1>Delays until reviews are shown again.<table width=400><tr><td width=200 align=right>Average interval:</td><td><b>1.5 months</b></td></tr><tr><td width=200 align=right>Longest interval:</td><td><b>2 years</b></td></tr></table></div>
+<div class=section><h1>Answer Buttons</h1>The number of times you have pressed each button.<table cellpadding=0 cellspacing=10><tr><td><div id="ease" style="width:600px; height:200px;"></div></td></tr></table>
+<center><table width=600px><tr><td width=50></td><td align=center>Correct: <u>85.00%</u><br>(850 of 1000)</td><td align=center>Correct: <u>90.00%</u><br>(900 of 1000)</td><td align=center>Correct: <u>95.00%</u><br>(1900 of 2000)</td></tr></table></center></div>
+<div class=section><h1>Card Types</h1>The division of cards in your deck(s).<table width=600><tr><td><table cellpadding=0 cellspacing=10><tr><td><div id="cards" style="width:300px; height:200px;"></div></td></tr></table>
+<script>$(function () { $.plot($("#cards"), [{"data": 3000, "label": "Mature: 3000", "color": "#007700"}, {"data": 1500, "label": "Young+Learn: 1500", "color": "#77cc77"}, {"data": 400, "label": "Unseen: 400", "color": "#000088"}, {"data": 100, "label": "Suspended+Buried: 100", "color": "#ffff33"}], {}); });</script>
+</td><td><table width=100%><tr><td width=200 align=right>Total cards:</td><td><b>5000</b></td></tr><tr><td width=200 align=right>Total notes:</td><td><b>2500</b></td></tr><tr><td width=200 align=right>Lowest ease:</td><td><b>130%</b></td></tr><tr><td width=200 align=right>Average ease:</td><td><b>245%</b></td></tr><tr><td width=200 align=right>Highest ease:</td><td><b>310%</b></td></tr></table><p>A card's <i>ease</i> is the size of the next interval when you answer "good" on a review.</td></table></div>
+<div class=section><center><font size=1>Generated on 2026-10-19 12:00</font></center></div></center>"##;
+
+    #[test]
+    fn parse_report() {
+        let stats = CollectionStats::parse(REPORT);
+        assert_eq!(stats.missing, Vec::<&str>::new());
+        assert!(stats.is_complete());
+
+        assert_eq!(stats.today, TodayStats {
+            cards_studied: Some(1024),
+            time_studied: Some(Duration::from_secs(5400)),
+            again_count: Some(100),
+            learn: Some(200),
+            review: Some(800),
+            relearn: Some(24),
+            filtered: Some(0),
+        });
+        assert_eq!(stats.future_due, FutureDueStats {
+            total: Some(5000),
+            average_per_day: Some(166.7),
+            due_tomorrow: Some(320),
+        });
+        assert_eq!(stats.review_count.days_studied, Some(25));
+        assert_eq!(stats.review_count.days_in_period, Some(31));
+        assert_eq!(stats.review_count.total, Some(12_345));
+        assert_eq!(stats.review_count.average_for_days_studied, Some(493.8));
+        assert_eq!(stats.review_count.average_every_day, Some(398.2));
+        assert_eq!(stats.intervals, IntervalStats { average_days: Some(45.0), longest_days: Some(730.0) });
+        assert_eq!(stats.card_counts, CardCountStats {
+            total_cards: Some(5000),
+            total_notes: Some(2500),
+            mature: Some(3000),
+            young_and_learning: Some(1500),
+            unseen: Some(400),
+            suspended: Some(100),
+        });
+        assert_eq!(stats.ease, EaseStats { lowest: Some(130.0), average: Some(245.0), highest: Some(310.0) });
+        assert_eq!(stats.answer_buttons.learning, Some(CorrectAnswers { correct: 850, total: 1000 }));
+        assert_eq!(stats.answer_buttons.mature, Some(CorrectAnswers { correct: 1900, total: 2000 }));
+    }
+
+    #[test]
+    fn report_missing_sections() {
+        let stats = CollectionStats::parse("<h1>Today</h1>No cards have been studied today.<h1>Intervals</h1>");
+        assert_eq!(stats.today.cards_studied, Some(0));
+        assert_eq!(stats.today.time_studied, Some(Duration::ZERO));
+        assert!(!stats.missing.contains(&"today.learn"));
+        assert!(stats.missing.contains(&"future_due.total"));
+        assert!(stats.missing.contains(&"intervals.average_days"));
+        assert_eq!(stats.intervals, IntervalStats::default());
+    }
+
+    #[test]
+    fn parse_x_of_y() {
+        assert_eq!(x_of_y("81% (25 of 31)"), Some((25, 31)));
+        assert_eq!(x_of_y("(1,900 of 2,000)"), Some((1900, 2000)));
+        assert_eq!(x_of_y("25 of 31"), None);
+    }
+
+    #[test]
+    fn parse_time_spans() {
+        assert_eq!(time_span("in 3.5 minutes"), Some(210.0));
+        assert_eq!(time_span("1 day"), Some(86_400.0));
+        assert_eq!(time_span("2 fortnights"), None);
+    }
+
+}
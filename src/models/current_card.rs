@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use crate::models::Ease;
+
+/// The card currently shown in the reviewer, as returned by `guiCurrentCard`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct CurrentCard {
+
+    #[serde(rename = "cardId")]
+    pub card_id: u64,
+
+    /// Rendered question (front) HTML
+    pub question: String,
+
+    /// Rendered answer (back) HTML
+    pub answer: String,
+
+    #[serde(rename = "deckName")]
+    pub deck_name: String,
+
+    #[serde(rename = "modelName")]
+    pub model_name: String,
+
+    /// Name of the card template
+    pub template: String,
+
+    /// Field contents, keyed by field name
+    pub fields: HashMap<String, CurrentCardField>,
+
+    /// Index of the sort field
+    #[serde(rename = "fieldOrder")]
+    pub field_order: usize,
+
+    /// Answer buttons available for this card
+    pub buttons: Vec<Ease>,
+
+    /// Next interval for each button, as displayed by Anki (e.g. `<10m`, `4d`)
+    #[serde(rename = "nextReviews")]
+    pub next_reviews: Vec<String>,
+
+    pub css: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct CurrentCardField {
+    pub value: String,
+    pub order: usize,
+}
+
+impl CurrentCard {
+
+    /// The displayed next interval for the given answer button, if the button is available.
+    pub fn next_review(&self, ease: Ease) -> Option<&str> {
+        self.buttons
+            .iter()
+            .position(|x| *x == ease)
+            .and_then(|i| self.next_reviews.get(i))
+            .map(String::as_str)
+    }
+
+    /// Field contents sorted by field order.
+    pub fn ordered_fields(&self) -> Vec<(&str, &str)> {
+        let mut fields: Vec<(&str, &CurrentCardField)> = self.fields
+            .iter()
+            .map(|(name, x)| (name.as_str(), x))
+            .collect();
+        fields.sort_by_key(|(_, x)| x.order);
+        fields.into_iter().map(|(name, x)| (name, x.value.as_str())).collect()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_current_card() {
+        let value = serde_json::json!({
+            "answer": "back content",
+            "question": "front content",
+            "deckName": "Default",
+            "modelName": "Basic",
+            "fieldOrder": 0,
+            "fields": {
+                "Front": {"value": "front content", "order": 0},
+                "Back": {"value": "back content", "order": 1}
+            },
+            "template": "Forward",
+            "cardId": 1498938915662u64,
+            "buttons": [1, 2, 3],
+            "nextReviews": ["<1m", "<10m", "4d"],
+            "css": "p {font-family:Arial;}"
+        });
+        let card = serde_json::from_value::<CurrentCard>(value).unwrap();
+        assert_eq!(card.buttons, vec![Ease::Again, Ease::Hard, Ease::Good]);
+        assert_eq!(card.next_review(Ease::Good), Some("4d"));
+        assert_eq!(card.next_review(Ease::Easy), None);
+        assert_eq!(card.ordered_fields(), vec![("Front", "front content"), ("Back", "back content")]);
+    }
+
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::models::ReviewDay;
+
+/// A count per day, e.g. the reviews reported by `getNumCardsReviewedByDay`.
+/// Days without an entry count as zero.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DailyCounts(pub BTreeMap<ReviewDay, u64>);
+
+impl DailyCounts {
+
+    /// Parses days reported as `YYYY-MM-DD`, as `getNumCardsReviewedByDay` returns them without
+    /// the `chrono` feature.
+    pub fn from_reported(reported: &[(String, u64)]) -> Result<Self, Error> {
+        let mut result = BTreeMap::new();
+        for (day, count) in reported {
+            *result.entry(day.parse::<ReviewDay>()?).or_insert(0) += count;
+        }
+        Ok(DailyCounts(result))
+    }
+
+    pub fn get(&self, day: ReviewDay) -> u64 {
+        self.0.get(&day).copied().unwrap_or(0)
+    }
+
+    pub fn first_day(&self) -> Option<ReviewDay> {
+        self.0.keys().next().copied()
+    }
+
+    pub fn last_day(&self) -> Option<ReviewDay> {
+        self.0.keys().next_back().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (ReviewDay, u64)> + '_ {
+        self.0.iter().map(|(day, count)| (*day, *count))
+    }
+
+    /// Every day from `from` to `to` inclusive, with days without an entry as zero.
+    pub fn range(&self, from: ReviewDay, to: ReviewDay) -> Vec<(ReviewDay, u64)> {
+        (from.0..=to.0)
+            .map(ReviewDay)
+            .map(|day| (day, self.get(day)))
+            .collect()
+    }
+
+    /// Every day from the first to the last entry, with days without an entry as zero.
+    pub fn with_zero_days(&self) -> Vec<(ReviewDay, u64)> {
+        match (self.first_day(), self.last_day()) {
+            (Some(from), Some(to)) => self.range(from, to),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Totals per week, keyed by the week's Monday.
+    pub fn by_week(&self) -> DailyCounts {
+        self.group_by(|day| ReviewDay(day.0 - i64::from(day.weekday())))
+    }
+
+    /// Totals per month, keyed by the first day of the month.
+    pub fn by_month(&self) -> DailyCounts {
+        self.group_by(|day| ReviewDay(day.0 - i64::from(day.ymd().2) + 1))
+    }
+
+    /// The trailing average over `window` days, for each day from the first to the last entry.
+    /// Days before the first entry count as zero.
+    pub fn moving_average(&self, window: usize) -> Vec<(ReviewDay, f64)> {
+        let window = window.max(1);
+        let days = self.with_zero_days();
+        let mut sum = 0;
+        days.iter()
+            .enumerate()
+            .map(|(index, (day, count))| {
+                sum += count;
+                if index >= window {
+                    sum -= days[index - window].1;
+                }
+                (*day, sum as f64 / window as f64)
+            })
+            .collect()
+    }
+
+    /// The entries as `chrono` dates.
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_dates(&self) -> Result<Vec<(chrono::NaiveDate, u64)>, Error> {
+        self.iter()
+            .map(|(day, count)| chrono::NaiveDate::try_from(day).map(|x| (x, count)))
+            .collect()
+    }
+
+    fn group_by(&self, key: impl Fn(ReviewDay) -> ReviewDay) -> DailyCounts {
+        let mut result = BTreeMap::new();
+        for (day, count) in self.iter() {
+            *result.entry(key(day)).or_insert(0) += count;
+        }
+        DailyCounts(result)
+    }
+
+}
+
+impl FromIterator<(ReviewDay, u64)> for DailyCounts {
+    fn from_iter<T: IntoIterator<Item = (ReviewDay, u64)>>(iter: T) -> Self {
+        let mut result = BTreeMap::new();
+        for (day, count) in iter {
+            *result.entry(day).or_insert(0) += count;
+        }
+        DailyCounts(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts() -> DailyCounts {
+        DailyCounts::from_reported(&[
+            ("2024-02-02".to_string(), 4),
+            ("2024-01-29".to_string(), 2),
+            ("2024-01-31".to_string(), 6),
+        ]).unwrap()
+    }
+
+    fn day(text: &str) -> ReviewDay {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn zero_days() {
+        let days: Vec<u64> = counts().with_zero_days().iter().map(|(_, x)| *x).collect();
+        assert_eq!(days, vec![2, 0, 6, 0, 4]);
+        assert!(DailyCounts::default().with_zero_days().is_empty());
+    }
+
+    #[test]
+    fn groups() {
+        let weeks: Vec<(ReviewDay, u64)> = counts().by_week().iter().collect();
+        assert_eq!(weeks, vec![(day("2024-01-29"), 12)]);
+
+        let months: Vec<(ReviewDay, u64)> = counts().by_month().iter().collect();
+        assert_eq!(months, vec![(day("2024-01-01"), 8), (day("2024-02-01"), 4)]);
+    }
+
+    #[test]
+    fn moving_average() {
+        let averages: Vec<f64> = counts().moving_average(2).iter().map(|(_, x)| *x).collect();
+        assert_eq!(averages, vec![1.0, 1.0, 3.0, 3.0, 2.0]);
+    }
+
+    #[test]
+    fn invalid_day() {
+        let result = DailyCounts::from_reported(&[("yesterday".to_string(), 1)]);
+        assert!(matches!(result, Err(Error::Invalid(_))));
+    }
+
+}
@@ -0,0 +1,202 @@
+use serde_json::{Map, Value};
+
+/// An options preset ("deck config") as returned by `getDeckConfig` and accepted by
+/// `saveDeckConfig`.
+///
+/// Only the commonly used settings are typed. Every other key is kept in `other`, so a config that
+/// is fetched, modified and saved back does not lose settings this crate does not know about (for
+/// example those added by newer Anki versions).
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DeckConfig {
+
+    /// Options group ID
+    pub id: u64,
+
+    pub name: String,
+
+    /// Settings for new cards
+    pub new: NewCardConfig,
+
+    /// Settings for lapsed cards (relearning)
+    pub lapse: LapseConfig,
+
+    /// Settings for review cards
+    pub rev: ReviewConfig,
+
+    /// Seconds after which the answer timer stops counting
+    #[serde(rename = "maxTaken", skip_serializing_if = "Option::is_none")]
+    pub max_taken: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autoplay: Option<bool>,
+
+    /// Whether to replay the question audio when the answer is shown
+    #[serde(rename = "replayq", skip_serializing_if = "Option::is_none")]
+    pub replay_question: Option<bool>,
+
+    /// FSRS parameters used by Anki 23.10 to 24.06
+    #[serde(rename = "fsrsWeights", skip_serializing_if = "Option::is_none")]
+    pub fsrs_weights: Option<Vec<f64>>,
+
+    /// FSRS-5 parameters, used by Anki 24.11 and later
+    #[serde(rename = "fsrsParams5", skip_serializing_if = "Option::is_none")]
+    pub fsrs_params_5: Option<Vec<f64>>,
+
+    /// FSRS-6 parameters, used by Anki 25.07 and later
+    #[serde(rename = "fsrsParams6", skip_serializing_if = "Option::is_none")]
+    pub fsrs_params_6: Option<Vec<f64>>,
+
+    /// Target retention for FSRS, between 0 and 1
+    #[serde(rename = "desiredRetention", skip_serializing_if = "Option::is_none")]
+    pub desired_retention: Option<f64>,
+
+    /// Every key not covered by a field above
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NewCardConfig {
+
+    /// Learning steps, in minutes
+    pub delays: Vec<f64>,
+
+    /// Maximum number of new cards introduced per day
+    #[serde(rename = "perDay")]
+    pub per_day: u32,
+
+    /// Graduating interval, easy interval (and an unused third value), in days
+    pub ints: Vec<u32>,
+
+    /// Starting ease, in permille (e.g. 2500 for 250%)
+    #[serde(rename = "initialFactor")]
+    pub initial_factor: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bury: Option<bool>,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LapseConfig {
+
+    /// Relearning steps, in minutes
+    pub delays: Vec<f64>,
+
+    /// Minimum interval after a lapse, in days
+    #[serde(rename = "minInt")]
+    pub min_interval: u32,
+
+    /// Multiplier applied to the interval after a lapse
+    pub mult: f64,
+
+    /// Number of lapses after which a card is considered a leech
+    #[serde(rename = "leechFails")]
+    pub leech_fails: u32,
+
+    /// 0 to suspend leeches, 1 to only tag them
+    #[serde(rename = "leechAction")]
+    pub leech_action: u8,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReviewConfig {
+
+    /// Maximum number of reviews per day
+    #[serde(rename = "perDay")]
+    pub per_day: u32,
+
+    /// Easy bonus
+    pub ease4: f64,
+
+    /// Interval modifier
+    #[serde(rename = "ivlFct")]
+    pub interval_factor: f64,
+
+    /// Maximum interval, in days
+    #[serde(rename = "maxIvl")]
+    pub max_interval: u32,
+
+    #[serde(rename = "hardFactor", skip_serializing_if = "Option::is_none")]
+    pub hard_factor: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bury: Option<bool>,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Value {
+        serde_json::json!({
+            "lapse": {
+                "leechFails": 8,
+                "delays": [10.0],
+                "minInt": 1,
+                "leechAction": 0,
+                "mult": 0.0
+            },
+            "dyn": false,
+            "autoplay": true,
+            "mod": 1502970872,
+            "id": 1,
+            "maxTaken": 60,
+            "new": {
+                "bury": true,
+                "order": 1,
+                "initialFactor": 2500,
+                "perDay": 20,
+                "delays": [1.0, 10.0],
+                "separate": true,
+                "ints": [1, 4, 7]
+            },
+            "name": "Default",
+            "rev": {
+                "bury": true,
+                "ivlFct": 1.0,
+                "ease4": 1.3,
+                "maxIvl": 36500,
+                "perDay": 100,
+                "minSpace": 1,
+                "fuzz": 0.05
+            },
+            "timer": 0,
+            "replayq": true,
+            "usn": -1,
+            "fsrsParams5": [0.4, 0.6, 2.4, 5.8],
+            "desiredRetention": 0.9,
+            "someFutureSetting": {"nested": [1, 2, 3]}
+        })
+    }
+
+    #[test]
+    fn deserialize_typed_fields() {
+        let config = serde_json::from_value::<DeckConfig>(example()).unwrap();
+        assert_eq!(config.name, "Default");
+        assert_eq!(config.new.per_day, 20);
+        assert_eq!(config.new.delays, vec![1.0, 10.0]);
+        assert_eq!(config.lapse.leech_fails, 8);
+        assert_eq!(config.rev.max_interval, 36500);
+        assert_eq!(config.desired_retention, Some(0.9));
+        assert_eq!(config.fsrs_params_6, None);
+    }
+
+    #[test]
+    fn round_trip_keeps_unknown_keys() {
+        let config = serde_json::from_value::<DeckConfig>(example()).unwrap();
+        assert!(config.other.contains_key("someFutureSetting"));
+        assert!(config.new.other.contains_key("separate"));
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(value, example());
+    }
+
+}
@@ -1,4 +1,4 @@
-#[derive(serde::Deserialize)]
+#[derive(Clone, Debug, serde::Deserialize)]
 pub struct DeckStats {
     pub deck_id: u64,
     pub name: String,
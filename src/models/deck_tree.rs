@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+
+use crate::models::DeckStats;
+
+/// Separator between the components of a deck name, e.g. `Lang::Japanese::Vocab`
+pub const DECK_SEPARATOR: &str = "::";
+
+/// The deck hierarchy, built from the flat deck names returned by `deckNames` or
+/// `deckNamesAndIds`.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use ankiconnect::models::DeckTree;
+///
+/// let decks = HashMap::from([
+///     ("Lang::Japanese::Vocab".to_string(), 3),
+///     ("Lang::Japanese".to_string(), 2),
+///     ("Default".to_string(), 1),
+/// ]);
+///
+/// let tree = DeckTree::from_names_and_ids(&decks);
+///
+/// // `Lang` was never listed, but exists implicitly as a parent
+/// let lang = tree.find("Lang").unwrap();
+/// assert_eq!(lang.id, None);
+/// assert_eq!(lang.descendants().count(), 2);
+/// assert_eq!(tree.find("Lang::Japanese::Vocab").unwrap().id, Some(3));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DeckTree {
+    roots: Vec<DeckNode>,
+}
+
+/// A single deck within a `DeckTree`.
+#[derive(Clone, Debug)]
+pub struct DeckNode {
+
+    /// The last component of the deck's name, e.g. `Vocab`
+    pub name: String,
+
+    /// The complete name of the deck, e.g. `Lang::Japanese::Vocab`
+    pub full_name: String,
+
+    /// Deck ID, or `None` if it is not known, e.g. because the deck was only implied by the name
+    /// of a subdeck
+    pub id: Option<u64>,
+
+    /// Statistics for this deck alone, if attached with `DeckTree::attach_stats`
+    pub stats: Option<DeckStats>,
+
+    /// `false` if the deck was only implied by the name of a subdeck
+    listed: bool,
+
+    children: Vec<DeckNode>,
+}
+
+/// New, learning and review counts summed over a deck and its descendants.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeckCounts {
+    pub new_count: u64,
+    pub learn_count: u64,
+    pub review_count: u64,
+}
+
+impl DeckTree {
+
+    /// Build a tree from deck names alone. Every node will have an `id` of `None`, but listed decks
+    /// are still told apart from implicit parents.
+    pub fn from_names<S: AsRef<str>>(names: impl IntoIterator<Item = S>) -> Self {
+        let mut tree = Self::default();
+        for name in names {
+            tree.insert(name.as_ref(), None);
+        }
+        tree.sort();
+        tree
+    }
+
+    /// Build a tree from the response of `deckNamesAndIds`.
+    pub fn from_names_and_ids(decks: &HashMap<String, u64>) -> Self {
+        let mut tree = Self::default();
+        for (name, id) in decks {
+            tree.insert(name, Some(*id));
+        }
+        tree.sort();
+        tree
+    }
+
+    fn insert(&mut self, full_name: &str, id: Option<u64>) {
+
+        fn insert_into(siblings: &mut Vec<DeckNode>, full_name: &str, start: usize, id: Option<u64>) {
+            let rest = &full_name[start..];
+            let (component, end) = match rest.find(DECK_SEPARATOR) {
+                Some(x) => (&rest[..x], Some(start + x)),
+                None => (rest, None),
+            };
+
+            let index = match siblings.iter().position(|x| x.name == component) {
+                Some(x) => x,
+                None => {
+                    let prefix = &full_name[..end.unwrap_or(full_name.len())];
+                    siblings.push(DeckNode::new(component, prefix));
+                    siblings.len() - 1
+                }
+            };
+
+            let node = &mut siblings[index];
+            match end {
+                Some(x) => insert_into(&mut node.children, full_name, x + DECK_SEPARATOR.len(), id),
+                None => {
+                    node.id = id.or(node.id);
+                    node.listed = true;
+                },
+            }
+        }
+
+        insert_into(&mut self.roots, full_name, 0, id);
+    }
+
+    fn sort(&mut self) {
+        fn sort_nodes(nodes: &mut [DeckNode]) {
+            nodes.sort_by(|a, b| a.name.cmp(&b.name));
+            for node in nodes {
+                sort_nodes(&mut node.children);
+            }
+        }
+        sort_nodes(&mut self.roots);
+    }
+
+    /// Top-level decks, sorted by name.
+    pub fn roots(&self) -> &[DeckNode] {
+        &self.roots
+    }
+
+    /// Every deck in the tree, parents before their children.
+    pub fn iter(&self) -> Descendants<'_> {
+        Descendants::new(&self.roots)
+    }
+
+    /// Find a deck by its full name, e.g. `Lang::Japanese`.
+    pub fn find(&self, full_name: &str) -> Option<&DeckNode> {
+        let mut components = full_name.split(DECK_SEPARATOR);
+        let first = components.next()?;
+        let mut node = self.roots.iter().find(|x| x.name == first)?;
+        for component in components {
+            node = node.child(component)?;
+        }
+        Some(node)
+    }
+
+    /// Find a deck by its full name, e.g. `Lang::Japanese`.
+    pub fn find_mut(&mut self, full_name: &str) -> Option<&mut DeckNode> {
+        let mut components = full_name.split(DECK_SEPARATOR);
+        let first = components.next()?;
+        let mut node = self.roots.iter_mut().find(|x| x.name == first)?;
+        for component in components {
+            node = node.children.iter_mut().find(|x| x.name == component)?;
+        }
+        Some(node)
+    }
+
+    /// Attach the response of `getDeckStats` to the matching nodes.
+    /// Stats are matched by deck name. Stats for decks that are not in the tree are ignored.
+    pub fn attach_stats(&mut self, stats: impl IntoIterator<Item = (u64, DeckStats)>) {
+        for (_, x) in stats {
+            if let Some(node) = self.find_mut(&x.name) {
+                node.id = node.id.or(Some(x.deck_id));
+                node.listed = true;
+                node.stats = Some(x);
+            }
+        }
+    }
+
+}
+
+impl<'a> IntoIterator for &'a DeckTree {
+    type Item = &'a DeckNode;
+    type IntoIter = Descendants<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl DeckNode {
+
+    fn new(name: &str, full_name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            full_name: full_name.to_string(),
+            id: None,
+            stats: None,
+            listed: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// `true` if the deck was only implied by the name of a subdeck.
+    pub fn is_implicit(&self) -> bool {
+        !self.listed
+    }
+
+    /// Direct subdecks, sorted by name.
+    pub fn children(&self) -> &[DeckNode] {
+        &self.children
+    }
+
+    /// Find a direct subdeck by the last component of its name.
+    pub fn child(&self, name: &str) -> Option<&DeckNode> {
+        self.children.iter().find(|x| x.name == name)
+    }
+
+    /// Every subdeck below this deck, parents before their children.
+    /// Does not include this deck.
+    pub fn descendants(&self) -> Descendants<'_> {
+        Descendants::new(&self.children)
+    }
+
+    /// The counts of this deck and all of its descendants, added together.
+    /// Decks without attached stats count as zero.
+    ///
+    /// Anki applies daily limits to each deck separately, so this may differ from the counts
+    /// Anki displays for the parent deck.
+    pub fn aggregated_counts(&self) -> DeckCounts {
+        std::iter::once(self)
+            .chain(self.descendants())
+            .filter_map(|x| x.stats.as_ref())
+            .fold(DeckCounts::default(), |acc, x| DeckCounts {
+                new_count: acc.new_count + x.new_count,
+                learn_count: acc.learn_count + x.learn_count,
+                review_count: acc.review_count + x.review_count,
+            })
+    }
+
+}
+
+/// Pre-order iterator over decks. See `DeckTree::iter` and `DeckNode::descendants`.
+pub struct Descendants<'a> {
+    stack: Vec<&'a DeckNode>,
+}
+
+impl<'a> Descendants<'a> {
+    fn new(nodes: &'a [DeckNode]) -> Self {
+        Self {
+            stack: nodes.iter().rev().collect(),
+        }
+    }
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = &'a DeckNode;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children.iter().rev());
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(deck_id: u64, name: &str, new_count: u64, learn_count: u64, review_count: u64) -> (u64, DeckStats) {
+        (deck_id, DeckStats {
+            deck_id,
+            name: name.to_string(),
+            new_count,
+            learn_count,
+            review_count,
+            total_in_deck: new_count + learn_count + review_count,
+        })
+    }
+
+    fn example() -> DeckTree {
+        DeckTree::from_names_and_ids(&HashMap::from([
+            ("Default".to_string(), 1),
+            ("Lang::Japanese".to_string(), 2),
+            ("Lang::Japanese::Vocab".to_string(), 3),
+            ("Lang::Japanese::Kanji".to_string(), 4),
+            ("Lang::German".to_string(), 5),
+        ]))
+    }
+
+    #[test]
+    fn implicit_parent() {
+        let tree = example();
+        let lang = tree.find("Lang").unwrap();
+        assert!(lang.is_implicit());
+        assert_eq!(lang.full_name, "Lang");
+        assert_eq!(tree.find("Lang::Japanese").unwrap().id, Some(2));
+    }
+
+    #[test]
+    fn implicit_parent_from_names() {
+        let tree = DeckTree::from_names(["Lang::Japanese", "Default"]);
+        assert!(tree.find("Lang").unwrap().is_implicit());
+        assert!(!tree.find("Lang::Japanese").unwrap().is_implicit());
+        assert!(!tree.find("Default").unwrap().is_implicit());
+        assert_eq!(tree.find("Default").unwrap().id, None);
+    }
+
+    #[test]
+    fn find_missing() {
+        let tree = example();
+        assert!(tree.find("Lang::French").is_none());
+        assert!(tree.find("Japanese").is_none());
+        assert!(tree.find("").is_none());
+    }
+
+    #[test]
+    fn pre_order_sorted() {
+        let tree = example();
+        let names: Vec<&str> = tree.iter().map(|x| x.full_name.as_str()).collect();
+        assert_eq!(names, vec![
+            "Default",
+            "Lang",
+            "Lang::German",
+            "Lang::Japanese",
+            "Lang::Japanese::Kanji",
+            "Lang::Japanese::Vocab",
+        ]);
+    }
+
+    #[test]
+    fn children_and_descendants() {
+        let tree = example();
+        let lang = tree.find("Lang").unwrap();
+        let children: Vec<&str> = lang.children().iter().map(|x| x.name.as_str()).collect();
+        assert_eq!(children, vec!["German", "Japanese"]);
+        assert_eq!(lang.descendants().count(), 4);
+        assert_eq!(tree.find("Default").unwrap().descendants().count(), 0);
+    }
+
+    #[test]
+    fn aggregate_counts() {
+        let mut tree = example();
+        tree.attach_stats([
+            stats(2, "Lang::Japanese", 1, 0, 2),
+            stats(3, "Lang::Japanese::Vocab", 10, 1, 5),
+            stats(4, "Lang::Japanese::Kanji", 5, 2, 0),
+            stats(5, "Lang::German", 0, 0, 7),
+            stats(99, "Not::In::Tree", 100, 100, 100),
+        ]);
+        assert_eq!(tree.find("Lang").unwrap().aggregated_counts(), DeckCounts {
+            new_count: 16,
+            learn_count: 3,
+            review_count: 14,
+        });
+        assert_eq!(tree.find("Lang::Japanese::Vocab").unwrap().aggregated_counts().new_count, 10);
+        assert_eq!(tree.find("Default").unwrap().aggregated_counts(), DeckCounts::default());
+    }
+
+}
@@ -0,0 +1,166 @@
+use crate::error::Error;
+
+/// The `days` argument of the `setDueDate` action.
+///
+/// Anki's syntax is `N` for a single day relative to today (`0` is today), `N-M` for a random day
+/// within an inclusive range, and a trailing `!` to also set the card's interval to the new due
+/// date.
+///
+/// ```
+/// use ankiconnect::models::DueDateSpec;
+///
+/// let spec = DueDateSpec::range(1, 7).unwrap().with_interval();
+/// assert_eq!(spec.to_string(), "1-7!");
+/// assert_eq!("1-7!".parse::<DueDateSpec>().unwrap(), spec);
+///
+/// assert!("7-1".parse::<DueDateSpec>().is_err());
+/// assert!("-1".parse::<DueDateSpec>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DueDateSpec {
+    start: u32,
+    end: u32,
+    set_interval: bool,
+}
+
+impl DueDateSpec {
+
+    /// Due on a single day, `days` from today.
+    pub fn day(days: u32) -> Self {
+        Self {
+            start: days,
+            end: days,
+            set_interval: false,
+        }
+    }
+
+    /// Due today.
+    pub fn today() -> Self {
+        Self::day(0)
+    }
+
+    /// Due on a random day between `start` and `end` days from today, inclusive.
+    /// Fails if the range is reversed.
+    pub fn range(start: u32, end: u32) -> Result<Self, Error> {
+        if start > end {
+            return Err(Error::Invalid(format!("due date range {start}-{end} is reversed")));
+        }
+        Ok(Self {
+            start,
+            end,
+            set_interval: false,
+        })
+    }
+
+    /// Also set the interval of the cards to the new due date.
+    pub fn with_interval(mut self) -> Self {
+        self.set_interval = true;
+        self
+    }
+
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    pub fn sets_interval(&self) -> bool {
+        self.set_interval
+    }
+
+}
+
+impl std::fmt::Display for DueDateSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)?;
+        } else {
+            write!(f, "{}-{}", self.start, self.end)?;
+        }
+        if self.set_interval {
+            write!(f, "!")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for DueDateSpec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+
+        let invalid = || Error::Invalid(format!("invalid due date spec: {s:?}"));
+
+        // Only plain digits are accepted, which also rules out signs and whitespace
+        let parse_days = |x: &str| -> Result<u32, Error> {
+            if x.is_empty() || !x.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            x.parse::<u32>().map_err(|_| invalid())
+        };
+
+        let (days, set_interval) = match s.strip_suffix('!') {
+            Some(x) => (x, true),
+            None => (s, false),
+        };
+
+        let spec = match days.split_once('-') {
+            Some((start, end)) => Self::range(parse_days(start)?, parse_days(end)?)?,
+            None => Self::day(parse_days(days)?),
+        };
+
+        if set_interval {
+            Ok(spec.with_interval())
+        } else {
+            Ok(spec)
+        }
+    }
+}
+
+impl serde::Serialize for DueDateSpec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_single_day() {
+        assert_eq!(DueDateSpec::today().to_string(), "0");
+        assert_eq!(DueDateSpec::day(3).with_interval().to_string(), "3!");
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        for s in ["0", "1", "1-7", "0-0!", "5!", "10-20!"] {
+            let spec = s.parse::<DueDateSpec>().unwrap();
+            let expected = if s == "0-0!" { "0!" } else { s };
+            assert_eq!(spec.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        for s in ["", "!", "-1", "1--2", "7-1", "1-", "-", "+1", " 1", "1!!", "a", "1-7!x"] {
+            assert!(s.parse::<DueDateSpec>().is_err(), "accepted {s:?}");
+        }
+    }
+
+    #[test]
+    fn reversed_range() {
+        assert!(DueDateSpec::range(7, 1).is_err());
+    }
+
+    #[test]
+    fn serialize_as_string() {
+        let spec = DueDateSpec::range(1, 7).unwrap();
+        let body = serde_json::to_value(spec).unwrap();
+        assert_eq!(body, serde_json::json!("1-7"));
+    }
+
+}
@@ -0,0 +1,63 @@
+/// The answer button pressed when reviewing a card.
+/// Serializes to the integer Anki uses for each button.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Ease {
+    Again = 1,
+    Hard = 2,
+    Good = 3,
+    Easy = 4,
+}
+
+impl Ease {
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl TryFrom<u8> for Ease {
+    type Error = u8;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Ease::Again),
+            2 => Ok(Ease::Hard),
+            3 => Ok(Ease::Good),
+            4 => Ok(Ease::Easy),
+            x => Err(x),
+        }
+    }
+}
+
+impl serde::Serialize for Ease {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Ease {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = u8::deserialize(deserializer)?;
+        Ease::try_from(value).map_err(|x| serde::de::Error::invalid_value(
+            serde::de::Unexpected::Unsigned(x.into()),
+            &"an ease between 1 and 4",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_good() {
+        let body = serde_json::to_value(Ease::Good).unwrap();
+        assert_eq!(body, serde_json::json!(3));
+    }
+
+    #[test]
+    fn try_from_out_of_range() {
+        assert_eq!(Ease::try_from(0), Err(0));
+        assert_eq!(Ease::try_from(5), Err(5));
+        assert_eq!(Ease::try_from(4), Ok(Ease::Easy));
+    }
+
+}
@@ -0,0 +1,43 @@
+/// Some actions reply with an empty object in place of a result they could not find, e.g.
+/// `cardsInfo` for a card that does not exist. This type decodes `{}` as `None` and anything else
+/// as `Some(T)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmptyOr<T>(pub Option<T>);
+
+impl<T> EmptyOr<T> {
+    pub fn into_option(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> From<EmptyOr<T>> for Option<T> {
+    fn from(value: EmptyOr<T>) -> Self {
+        value.0
+    }
+}
+
+impl<'de, T: serde::de::DeserializeOwned> serde::Deserialize<'de> for EmptyOr<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match &value {
+            serde_json::Value::Object(x) if x.is_empty() => Ok(EmptyOr(None)),
+            _ => serde_json::from_value(value)
+                .map(|x| EmptyOr(Some(x)))
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_empty() {
+        let value = serde_json::json!([{}, {"a": 1}]);
+        let result = serde_json::from_value::<Vec<EmptyOr<std::collections::HashMap<String, u8>>>>(value).unwrap();
+        assert_eq!(result[0], EmptyOr(None));
+        assert_eq!(result[1].clone().into_option().unwrap()["a"], 1);
+    }
+
+}
@@ -0,0 +1,57 @@
+/// Some actions reply with `false` instead of a result when they fail, e.g. `getDeckConfig` when
+/// the deck does not exist. This type decodes `false` as `None` and anything else as `Some(T)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FalseOr<T>(pub Option<T>);
+
+impl<T> FalseOr<T> {
+    pub fn into_option(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> From<FalseOr<T>> for Option<T> {
+    fn from(value: FalseOr<T>) -> Self {
+        value.0
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for FalseOr<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Inner<T> {
+            Bool(bool),
+            Value(T),
+        }
+
+        match Inner::<T>::deserialize(deserializer)? {
+            Inner::Bool(false) => Ok(FalseOr(None)),
+            Inner::Bool(true) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Bool(true),
+                &"false or a result",
+            )),
+            Inner::Value(x) => Ok(FalseOr(Some(x))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_false() {
+        let value = serde_json::json!(false);
+        let result = serde_json::from_value::<FalseOr<u64>>(value).unwrap();
+        assert_eq!(result.into_option(), None);
+    }
+
+    #[test]
+    fn deserialize_value() {
+        let value = serde_json::json!(1502972374573u64);
+        let result = serde_json::from_value::<FalseOr<u64>>(value).unwrap();
+        assert_eq!(result.into_option(), Some(1502972374573));
+    }
+
+}
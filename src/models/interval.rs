@@ -0,0 +1,99 @@
+/// Represents a card interval as reported by Anki.
+/// Anki stores intervals as a signed integer, where positive values are days and negative values
+/// are seconds (used while a card is in (re)learning).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Interval {
+    Days(u32),
+    Seconds(u32),
+}
+
+impl Interval {
+
+    /// Decode Anki's signed representation.
+    pub fn from_raw(value: i32) -> Self {
+        if value < 0 {
+            Interval::Seconds(value.unsigned_abs())
+        } else {
+            Interval::Days(value as u32)
+        }
+    }
+
+    /// Encode back into Anki's signed representation.
+    /// Values that do not fit into an `i32` are saturated.
+    pub fn as_raw(&self) -> i32 {
+        match *self {
+            Interval::Days(x) => i32::try_from(x).unwrap_or(i32::MAX),
+            Interval::Seconds(x) => i32::try_from(x).map(|x| -x).unwrap_or(i32::MIN),
+        }
+    }
+
+    /// The interval in seconds, regardless of which unit Anki used to store it.
+    pub fn as_seconds(&self) -> u64 {
+        match *self {
+            Interval::Days(x) => u64::from(x) * 86_400,
+            Interval::Seconds(x) => u64::from(x),
+        }
+    }
+
+}
+
+impl From<i32> for Interval {
+    fn from(value: i32) -> Self {
+        Interval::from_raw(value)
+    }
+}
+
+impl From<Interval> for i32 {
+    fn from(value: Interval) -> Self {
+        value.as_raw()
+    }
+}
+
+impl serde::Serialize for Interval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_i32(self.as_raw())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Interval {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        i32::deserialize(deserializer).map(Interval::from_raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_days() {
+        let value = serde_json::json!(3);
+        let interval = serde_json::from_value::<Interval>(value).unwrap();
+        assert_eq!(interval, Interval::Days(3));
+    }
+
+    #[test]
+    fn deserialize_seconds() {
+        let value = serde_json::json!(-14400);
+        let interval = serde_json::from_value::<Interval>(value).unwrap();
+        assert_eq!(interval, Interval::Seconds(14400));
+    }
+
+    #[test]
+    fn deserialize_history() {
+        let value = serde_json::json!([[-120, -14400], [-120, 1, 3]]);
+        let history = serde_json::from_value::<Vec<Vec<Interval>>>(value).unwrap();
+        assert_eq!(history, vec![
+            vec![Interval::Seconds(120), Interval::Seconds(14400)],
+            vec![Interval::Seconds(120), Interval::Days(1), Interval::Days(3)],
+        ]);
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        for raw in [i32::MIN + 1, -14400, -1, 0, 1, 21, i32::MAX] {
+            assert_eq!(Interval::from_raw(raw).as_raw(), raw);
+        }
+    }
+
+}
@@ -3,8 +3,9 @@ use std::borrow::Cow;
 /// Represents the source of a media file, which could be an absolute path to a local file
 /// (relative to the Anki-Connect server), a URL to another server which is hosting the file, or a
 /// base64 encoded string.
-#[derive(serde::Serialize)]
-#[serde(untagged)]
+/// Serializes to a single-key map (e.g. `{"url": "..."}`), meant to be flattened into the
+/// surrounding object.
+#[derive(Clone, Debug, serde::Serialize)]
 pub enum MediaSource<'a> {
 
     /// Represents the case where the media source is a base64-encoded string, inline with the
@@ -23,3 +24,16 @@ pub enum MediaSource<'a> {
     Url(Cow<'a, String>),
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_url() {
+        let value = MediaSource::Url(Cow::Owned("https://example.com/a.mp3".to_string()));
+        let body = serde_json::to_value(value).unwrap();
+        assert_eq!(body, serde_json::json!({"url": "https://example.com/a.mp3"}));
+    }
+
+}
@@ -18,3 +18,63 @@ pub use media_source::MediaSource;
 
 pub mod deck_stats;
 pub use deck_stats::DeckStats;
+
+pub mod interval;
+pub use interval::Interval;
+
+pub mod ease;
+pub use ease::Ease;
+
+pub mod due_date_spec;
+pub use due_date_spec::DueDateSpec;
+
+pub mod false_or;
+pub use false_or::FalseOr;
+
+pub mod deck_config;
+pub use deck_config::DeckConfig;
+
+pub mod deck_tree;
+pub use deck_tree::DeckTree;
+
+pub mod card_template;
+pub use card_template::CardTemplate;
+
+pub mod model_info;
+pub use model_info::ModelInfo;
+
+pub mod model_spec;
+pub use model_spec::ModelSpec;
+
+pub mod note;
+pub use note::Note;
+
+pub mod current_card;
+pub use current_card::CurrentCard;
+
+pub mod empty_or;
+pub use empty_or::EmptyOr;
+
+pub mod card_info;
+pub use card_info::CardInfo;
+
+pub mod rating;
+pub use rating::Rating;
+
+pub mod review_kind;
+pub use review_kind::ReviewKind;
+
+pub mod review_timestamp;
+pub use review_timestamp::ReviewTimestamp;
+
+pub mod review_log_entry;
+pub use review_log_entry::ReviewLogEntry;
+
+pub mod review_day;
+pub use review_day::ReviewDay;
+
+pub mod daily_counts;
+pub use daily_counts::DailyCounts;
+
+pub mod collection_stats;
+pub use collection_stats::CollectionStats;
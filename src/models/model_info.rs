@@ -0,0 +1,164 @@
+use serde_json::{Map, Value};
+
+/// A model (note type), as returned by `findModelsById` and `findModelsByName`.
+/// Keys that are not typed here are kept in `other`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModelInfo {
+
+    pub id: u64,
+
+    pub name: String,
+
+    /// 0 for standard models, 1 for cloze models
+    #[serde(rename = "type")]
+    pub kind: u8,
+
+    /// Fields, in order
+    #[serde(rename = "flds")]
+    pub fields: Vec<ModelFieldInfo>,
+
+    /// Card templates, in order
+    #[serde(rename = "tmpls")]
+    pub templates: Vec<ModelTemplateInfo>,
+
+    pub css: String,
+
+    /// Index of the field used to sort in the browser
+    #[serde(rename = "sortf")]
+    pub sort_field: usize,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl ModelInfo {
+
+    pub fn is_cloze(&self) -> bool {
+        self.kind == 1
+    }
+
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|x| x.name.as_str())
+    }
+
+    pub fn template_names(&self) -> impl Iterator<Item = &str> {
+        self.templates.iter().map(|x| x.name.as_str())
+    }
+
+}
+
+/// A single field of a `ModelInfo`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModelFieldInfo {
+
+    pub name: String,
+
+    /// Position of the field, starting at 0
+    pub ord: usize,
+
+    /// Editor font
+    pub font: String,
+
+    /// Editor font size
+    pub size: u32,
+
+    #[serde(default)]
+    pub description: String,
+
+    #[serde(default)]
+    pub sticky: bool,
+
+    #[serde(default)]
+    pub rtl: bool,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// A single card template of a `ModelInfo`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModelTemplateInfo {
+
+    pub name: String,
+
+    /// Position of the template, starting at 0
+    pub ord: usize,
+
+    /// Front template
+    #[serde(rename = "qfmt")]
+    pub front: String,
+
+    /// Back template
+    #[serde(rename = "afmt")]
+    pub back: String,
+
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_basic() {
+        let value = serde_json::json!({
+            "id": 1704387367119u64,
+            "name": "Basic",
+            "type": 0,
+            "mod": 1704387367,
+            "usn": -1,
+            "sortf": 0,
+            "did": null,
+            "tmpls": [{
+                "name": "Card 1",
+                "ord": 0,
+                "qfmt": "{{Front}}",
+                "afmt": "{{FrontSide}}<hr id=answer>{{Back}}",
+                "bqfmt": "",
+                "bafmt": "",
+                "did": null,
+                "bfont": "",
+                "bsize": 0,
+                "id": 9176047152973362695u64
+            }],
+            "flds": [{
+                "name": "Front",
+                "ord": 0,
+                "sticky": false,
+                "rtl": false,
+                "font": "Arial",
+                "size": 20,
+                "description": "",
+                "plainText": false,
+                "collapsed": false,
+                "excludeFromSearch": false,
+                "id": 2453723143453745216u64,
+                "tag": null,
+                "preventDeletion": false
+            }, {
+                "name": "Back",
+                "ord": 1,
+                "sticky": false,
+                "rtl": false,
+                "font": "Arial",
+                "size": 20,
+                "description": "The answer"
+            }],
+            "css": ".card { color: black; }",
+            "latexPre": "",
+            "latexPost": "",
+            "latexsvg": false,
+            "req": [[0, "any", [0]]],
+            "originalStockKind": 1
+        });
+        let model = serde_json::from_value::<ModelInfo>(value.clone()).unwrap();
+        assert!(!model.is_cloze());
+        assert_eq!(model.field_names().collect::<Vec<_>>(), vec!["Front", "Back"]);
+        assert_eq!(model.template_names().collect::<Vec<_>>(), vec!["Card 1"]);
+        assert_eq!(model.templates[0].front, "{{Front}}");
+        assert_eq!(model.fields[1].description, "The answer");
+        assert_eq!(serde_json::to_value(&model).unwrap(), value);
+    }
+
+}
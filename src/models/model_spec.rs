@@ -0,0 +1,126 @@
+use crate::models::CardTemplate;
+
+/// The desired state of a model (note type), to be reconciled with Anki by
+/// `operations::ModelPlan`.
+///
+/// ```
+/// use ankiconnect::models::{CardTemplate, ModelSpec};
+///
+/// let spec = ModelSpec::new("Vocab")
+///     .field("Word")
+///     .renamed_field("Meaning", ["Definition"])
+///     .template("Recognition", CardTemplate::new("{{Word}}", "{{FrontSide}}<hr>{{Meaning}}"))
+///     .css(".card { font-size: 24px; }");
+///
+/// assert_eq!(spec.field_names().collect::<Vec<_>>(), vec!["Word", "Meaning"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ModelSpec {
+
+    pub name: String,
+
+    /// Fields, in order
+    pub fields: Vec<FieldSpec>,
+
+    /// Card templates, in order
+    pub templates: Vec<TemplateSpec>,
+
+    pub css: String,
+
+    #[serde(default)]
+    pub is_cloze: bool,
+}
+
+/// A field of a `ModelSpec`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FieldSpec {
+
+    pub name: String,
+
+    /// Names this field had before. If Anki has a field by one of these names, it is renamed
+    /// rather than removed and added again, which keeps its contents.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub renamed_from: Vec<String>,
+}
+
+/// A card template of a `ModelSpec`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TemplateSpec {
+
+    pub name: String,
+
+    #[serde(flatten)]
+    pub template: CardTemplate,
+
+    /// Names this template had before. If Anki has a template by one of these names, it is
+    /// renamed rather than removed and added again, which keeps its cards.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub renamed_from: Vec<String>,
+}
+
+impl ModelSpec {
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Append a field.
+    pub fn field(self, name: impl Into<String>) -> Self {
+        self.renamed_field(name, Vec::<String>::new())
+    }
+
+    /// Append a field that used to be called one of `renamed_from`.
+    pub fn renamed_field<S: Into<String>>(
+        mut self,
+        name: impl Into<String>,
+        renamed_from: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.fields.push(FieldSpec {
+            name: name.into(),
+            renamed_from: renamed_from.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Append a card template.
+    pub fn template(self, name: impl Into<String>, template: CardTemplate) -> Self {
+        self.renamed_template(name, template, Vec::<String>::new())
+    }
+
+    /// Append a card template that used to be called one of `renamed_from`.
+    pub fn renamed_template<S: Into<String>>(
+        mut self,
+        name: impl Into<String>,
+        template: CardTemplate,
+        renamed_from: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.templates.push(TemplateSpec {
+            name: name.into(),
+            template,
+            renamed_from: renamed_from.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    pub fn css(mut self, css: impl Into<String>) -> Self {
+        self.css = css.into();
+        self
+    }
+
+    pub fn cloze(mut self, is_cloze: bool) -> Self {
+        self.is_cloze = is_cloze;
+        self
+    }
+
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|x| x.name.as_str())
+    }
+
+    pub fn template_names(&self) -> impl Iterator<Item = &str> {
+        self.templates.iter().map(|x| x.name.as_str())
+    }
+
+}
@@ -0,0 +1,153 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::models::MediaSource;
+
+/// A note that has not been added yet, as accepted by `guiAddCards`.
+///
+/// ```
+/// use ankiconnect::models::Note;
+///
+/// let note = Note::new("Default", "Basic")
+///     .field("Front", "front content")
+///     .field("Back", "back content")
+///     .tag("imported");
+/// ```
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Note<'a> {
+
+    #[serde(rename = "deckName")]
+    pub deck_name: Cow<'a, str>,
+
+    #[serde(rename = "modelName")]
+    pub model_name: Cow<'a, str>,
+
+    /// Field contents, keyed by field name
+    pub fields: HashMap<Cow<'a, str>, Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub audio: Vec<NoteMedia<'a>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub video: Vec<NoteMedia<'a>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub picture: Vec<NoteMedia<'a>>,
+}
+
+impl<'a> Note<'a> {
+
+    pub fn new(
+        deck_name: impl Into<Cow<'a, str>>,
+        model_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            deck_name: deck_name.into(),
+            model_name: model_name.into(),
+            fields: HashMap::new(),
+            tags: Vec::new(),
+            audio: Vec::new(),
+            video: Vec::new(),
+            picture: Vec::new(),
+        }
+    }
+
+    pub fn field(mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        self.fields.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<Cow<'a, str>>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn audio(mut self, media: NoteMedia<'a>) -> Self {
+        self.audio.push(media);
+        self
+    }
+
+    pub fn video(mut self, media: NoteMedia<'a>) -> Self {
+        self.video.push(media);
+        self
+    }
+
+    pub fn picture(mut self, media: NoteMedia<'a>) -> Self {
+        self.picture.push(media);
+        self
+    }
+
+}
+
+/// A media file to store alongside a `Note`, and reference from some of its fields.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct NoteMedia<'a> {
+
+    #[serde(flatten)]
+    pub source: MediaSource<'a>,
+
+    /// Name the file is stored under in the media folder
+    pub filename: Cow<'a, str>,
+
+    /// Skip storing the file if its MD5 hash matches this value, e.g. for placeholder images
+    #[serde(rename = "skipHash", skip_serializing_if = "Option::is_none")]
+    pub skip_hash: Option<Cow<'a, str>>,
+
+    /// Fields the media should be appended to
+    pub fields: Vec<Cow<'a, str>>,
+}
+
+impl<'a> NoteMedia<'a> {
+
+    pub fn new(source: MediaSource<'a>, filename: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            source,
+            filename: filename.into(),
+            skip_hash: None,
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn with_field(mut self, field: impl Into<Cow<'a, str>>) -> Self {
+        self.fields.push(field.into());
+        self
+    }
+
+    pub fn with_skip_hash(mut self, skip_hash: impl Into<Cow<'a, str>>) -> Self {
+        self.skip_hash = Some(skip_hash.into());
+        self
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_note_with_media() {
+        let note = Note::new("Default", "Basic")
+            .field("Front", "front content")
+            .tag("yomichan")
+            .audio(
+                NoteMedia::new(MediaSource::Url(Cow::Owned("https://example.com/a.mp3".to_string())), "a.mp3")
+                    .with_field("Front")
+            );
+        let body = serde_json::to_value(note).unwrap();
+        assert_eq!(body, serde_json::json!({
+            "deckName": "Default",
+            "modelName": "Basic",
+            "fields": {"Front": "front content"},
+            "tags": ["yomichan"],
+            "audio": [{
+                "url": "https://example.com/a.mp3",
+                "filename": "a.mp3",
+                "fields": ["Front"],
+            }],
+        }));
+    }
+
+}
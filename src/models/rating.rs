@@ -0,0 +1,90 @@
+use strum::{AsRefStr, EnumString};
+use crate::models::Ease;
+
+/// The rating recorded in a review log entry.
+/// This is the answer button for regular reviews, or `Manual` for entries that Anki records
+/// without an answer, e.g. when a card is rescheduled.
+/// Serializes to the integer Anki stores as the entry's `ease`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumString, AsRefStr)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum Rating {
+    Manual = 0,
+    Again = 1,
+    Hard = 2,
+    Good = 3,
+    Easy = 4,
+}
+
+impl Rating {
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    /// The answer button, or `None` for manual entries.
+    pub fn as_ease(&self) -> Option<Ease> {
+        Ease::try_from(self.as_u8()).ok()
+    }
+
+}
+
+impl From<Ease> for Rating {
+    fn from(value: Ease) -> Self {
+        match value {
+            Ease::Again => Rating::Again,
+            Ease::Hard => Rating::Hard,
+            Ease::Good => Rating::Good,
+            Ease::Easy => Rating::Easy,
+        }
+    }
+}
+
+impl TryFrom<u8> for Rating {
+    type Error = u8;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Rating::Manual),
+            1 => Ok(Rating::Again),
+            2 => Ok(Rating::Hard),
+            3 => Ok(Rating::Good),
+            4 => Ok(Rating::Easy),
+            x => Err(x),
+        }
+    }
+}
+
+impl serde::Serialize for Rating {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Rating {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = u8::deserialize(deserializer)?;
+        Rating::try_from(value).map_err(|x| serde::de::Error::invalid_value(
+            serde::de::Unexpected::Unsigned(x.into()),
+            &"a rating between 0 and 4",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ease_round_trip() {
+        for ease in [Ease::Again, Ease::Hard, Ease::Good, Ease::Easy] {
+            assert_eq!(Rating::from(ease).as_ease(), Some(ease));
+        }
+        assert_eq!(Rating::Manual.as_ease(), None);
+    }
+
+    #[test]
+    fn try_from_out_of_range() {
+        assert_eq!(Rating::try_from(0), Ok(Rating::Manual));
+        assert_eq!(Rating::try_from(5), Err(5));
+    }
+
+}
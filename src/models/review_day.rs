@@ -0,0 +1,132 @@
+use crate::error::Error;
+use crate::models::review_timestamp::{civil_from_days, days_from_civil};
+
+/// A calendar day, as a number of days from UNIX epoch.
+/// Formats as `YYYY-MM-DD`, which is also how `getNumCardsReviewedByDay` reports days.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReviewDay(pub i64);
+
+/// Years further from year 0 than this are rejected, well before the day count overflows.
+const MAX_YEAR: i64 = 1_000_000;
+
+impl ReviewDay {
+
+    pub fn from_ymd(year: i64, month: u32, day: u32) -> Result<Self, Error> {
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) || year.abs() > MAX_YEAR {
+            return Err(Error::Invalid(format!("invalid date {year:04}-{month:02}-{day:02}")));
+        }
+        let result = ReviewDay(days_from_civil(year, month, day));
+        if result.ymd() == (year, month, day) {
+            Ok(result)
+        } else {
+            Err(Error::Invalid(format!("invalid date {year:04}-{month:02}-{day:02}")))
+        }
+    }
+
+    pub fn ymd(&self) -> (i64, u32, u32) {
+        civil_from_days(self.0)
+    }
+
+    /// 0 for Monday, through 6 for Sunday.
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.0 + 3).rem_euclid(7) as u32
+    }
+
+    pub fn next(&self) -> Self {
+        ReviewDay(self.0 + 1)
+    }
+
+    pub fn previous(&self) -> Self {
+        ReviewDay(self.0 - 1)
+    }
+
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for ReviewDay {
+    fn from(value: chrono::NaiveDate) -> Self {
+        ReviewDay(value.signed_duration_since(chrono::DateTime::UNIX_EPOCH.date_naive()).num_days())
+    }
+}
+
+/// Fails for days outside of the range `NaiveDate` supports.
+#[cfg(feature = "chrono")]
+impl TryFrom<ReviewDay> for chrono::NaiveDate {
+    type Error = Error;
+    fn try_from(value: ReviewDay) -> Result<Self, Self::Error> {
+        chrono::TimeDelta::try_days(value.0)
+            .and_then(|x| chrono::DateTime::UNIX_EPOCH.date_naive().checked_add_signed(x))
+            .ok_or_else(|| Error::Invalid(format!("day {} is out of range", value.0)))
+    }
+}
+
+impl std::fmt::Display for ReviewDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = self.ymd();
+        write!(f, "{year:04}-{month:02}-{day:02}")
+    }
+}
+
+impl std::str::FromStr for ReviewDay {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::Invalid(format!("invalid date {s:?}, expected YYYY-MM-DD"));
+        let mut parts = s.splitn(3, '-');
+        let mut number = || -> Result<i64, Error> {
+            let part = parts.next().ok_or_else(invalid)?;
+            if part.is_empty() || !part.bytes().all(|x| x.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            part.parse().map_err(|_| invalid())
+        };
+        let (year, month, day) = (number()?, number()?, number()?);
+        let month = u32::try_from(month).map_err(|_| invalid())?;
+        let day = u32::try_from(day).map_err(|_| invalid())?;
+        ReviewDay::from_ymd(year, month, day).map_err(|_| invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        let day = "2024-02-29".parse::<ReviewDay>().unwrap();
+        assert_eq!(day.to_string(), "2024-02-29");
+        assert_eq!(day.next().to_string(), "2024-03-01");
+        assert_eq!(ReviewDay(0).to_string(), "1970-01-01");
+        for text in [
+            "2023-02-29",
+            "2023-1-",
+            "2023/01/01",
+            "20230101",
+            "2023-13-01",
+            "2023-00-01",
+            "2023-4294967295-01",
+            "2023-01-4294967295",
+            "9223372036854775807-01-01",
+        ] {
+            assert!(text.parse::<ReviewDay>().is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn weekday() {
+        // A Monday
+        assert_eq!("2024-01-01".parse::<ReviewDay>().unwrap().weekday(), 0);
+        assert_eq!(ReviewDay(0).weekday(), 3);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_round_trip() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let day = ReviewDay::from(date);
+        assert_eq!(day.to_string(), "2024-02-29");
+        assert_eq!(chrono::NaiveDate::try_from(day).unwrap(), date);
+        assert!(chrono::NaiveDate::try_from(ReviewDay(i64::MAX)).is_err());
+    }
+
+}
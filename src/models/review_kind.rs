@@ -0,0 +1,74 @@
+use strum::{AsRefStr, EnumString};
+
+/// The kind of a review log entry.
+/// Serializes to the integer Anki stores as the entry's `type`.
+/// Backend type: `RevlogReviewKind`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumString, AsRefStr)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum ReviewKind {
+    Learning = 0,
+    Review = 1,
+    Relearning = 2,
+    /// Aka. `EarlyReview`, a review in a filtered deck before the card was due
+    Filtered = 3,
+    Manual = 4,
+    Rescheduled = 5,
+}
+
+impl ReviewKind {
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Whether the entry was recorded without the card being answered.
+    pub fn is_manual(&self) -> bool {
+        matches!(self, ReviewKind::Manual | ReviewKind::Rescheduled)
+    }
+
+}
+
+impl TryFrom<u8> for ReviewKind {
+    type Error = u8;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ReviewKind::Learning),
+            1 => Ok(ReviewKind::Review),
+            2 => Ok(ReviewKind::Relearning),
+            3 => Ok(ReviewKind::Filtered),
+            4 => Ok(ReviewKind::Manual),
+            5 => Ok(ReviewKind::Rescheduled),
+            x => Err(x),
+        }
+    }
+}
+
+impl serde::Serialize for ReviewKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ReviewKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = u8::deserialize(deserializer)?;
+        ReviewKind::try_from(value).map_err(|x| serde::de::Error::invalid_value(
+            serde::de::Unexpected::Unsigned(x.into()),
+            &"a review kind between 0 and 5",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_round_trip() {
+        for raw in 0..=5 {
+            assert_eq!(ReviewKind::try_from(raw).unwrap().as_u8(), raw);
+        }
+        assert_eq!(ReviewKind::try_from(6), Err(6));
+    }
+
+}
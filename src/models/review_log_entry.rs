@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::models::card_review::{CardReview, CardReviewTuple, CardReviewWithoutCardId};
+use crate::models::{Interval, Rating, ReviewKind, ReviewTimestamp};
+
+/// A typed version of [`CardReview`].
+/// Converts to and from [`CardReviewTuple`] and [`CardReviewWithoutCardId`] without losing
+/// information. Converting from those fails if the rating or kind is not one Anki knows.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewLogEntry {
+
+    /// Also the review's ID
+    pub timestamp: ReviewTimestamp,
+
+    pub card_id: i64,
+
+    pub usn: i32,
+
+    pub rating: Rating,
+
+    pub new_interval: Interval,
+
+    pub last_interval: Interval,
+
+    /// In permille
+    pub new_factor: u32,
+
+    /// In milliseconds
+    pub review_duration: u32,
+
+    pub kind: ReviewKind,
+}
+
+impl ReviewLogEntry {
+
+    pub fn id(&self) -> i64 {
+        self.timestamp.as_millis()
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.review_duration.into())
+    }
+
+    pub fn from_tuple(value: &CardReviewTuple) -> Result<Self, Error> {
+        let (id, card_id, usn, ease, new_interval, last_interval, new_factor, review_duration, review_type) = *value;
+        Ok(Self {
+            timestamp: ReviewTimestamp(id),
+            card_id,
+            usn,
+            rating: Rating::try_from(ease)
+                .map_err(|x| Error::Invalid(format!("review {id} has invalid ease {x}")))?,
+            new_interval: Interval::from_raw(new_interval),
+            last_interval: Interval::from_raw(last_interval),
+            new_factor,
+            review_duration,
+            kind: ReviewKind::try_from(review_type)
+                .map_err(|x| Error::Invalid(format!("review {id} has invalid type {x}")))?,
+        })
+    }
+
+    pub fn as_tuple(&self) -> CardReviewTuple {
+        (
+            self.timestamp.as_millis(),
+            self.card_id,
+            self.usn,
+            self.rating.as_u8(),
+            self.new_interval.as_raw(),
+            self.last_interval.as_raw(),
+            self.new_factor,
+            self.review_duration,
+            self.kind.as_u8(),
+        )
+    }
+
+    /// For reviews returned by `getReviewsOfCards`, which are keyed by card ID.
+    pub fn from_without_card_id(value: &CardReviewWithoutCardId, card_id: i64) -> Result<Self, Error> {
+        Self::from_tuple(&value.with_card_id(card_id).as_tuple())
+    }
+
+}
+
+impl TryFrom<CardReviewTuple> for ReviewLogEntry {
+    type Error = Error;
+    fn try_from(value: CardReviewTuple) -> Result<Self, Self::Error> {
+        ReviewLogEntry::from_tuple(&value)
+    }
+}
+
+// By value: a `From<&ReviewLogEntry>` impl would make `InsertReviews::new_from(&[])` ambiguous
+impl From<ReviewLogEntry> for CardReviewTuple {
+    fn from(value: ReviewLogEntry) -> Self {
+        value.as_tuple()
+    }
+}
+
+impl TryFrom<&CardReviewTuple> for ReviewLogEntry {
+    type Error = Error;
+    fn try_from(value: &CardReviewTuple) -> Result<Self, Self::Error> {
+        ReviewLogEntry::from_tuple(value)
+    }
+}
+
+impl TryFrom<&CardReview> for ReviewLogEntry {
+    type Error = Error;
+    fn try_from(value: &CardReview) -> Result<Self, Self::Error> {
+        ReviewLogEntry::from_tuple(&value.as_tuple())
+    }
+}
+
+impl From<&ReviewLogEntry> for CardReview {
+    fn from(value: &ReviewLogEntry) -> Self {
+        CardReview::from_tuple(&value.as_tuple())
+    }
+}
+
+impl From<&ReviewLogEntry> for CardReviewWithoutCardId {
+    fn from(value: &ReviewLogEntry) -> Self {
+        CardReview::from(value).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tuple_round_trip() {
+        let tuples: [CardReviewTuple; 3] = [
+            (1_700_000_000_000, 2, -1, 3, -600, -60, 2500, 6000, 0),
+            (1_700_000_100_000, 2, 15, 1, 21, 7, 2300, 12_000, 1),
+            (1_700_000_200_000, 2, 15, 0, 0, 21, 0, 0, 5),
+        ];
+        for tuple in tuples {
+            let entry = ReviewLogEntry::from_tuple(&tuple).unwrap();
+            assert_eq!(entry.as_tuple(), tuple);
+            let entry = ReviewLogEntry::try_from(tuple).unwrap();
+            assert_eq!(CardReviewTuple::from(entry), tuple);
+        }
+    }
+
+    #[test]
+    fn without_card_id_round_trip() {
+        let value = serde_json::json!({
+            "id": 1_700_000_000_000_i64, "usn": -1, "ease": 2, "ivl": -600, "lastIvl": 3,
+            "factor": 2500, "time": 4000, "type": 2,
+        });
+        let review = serde_json::from_value::<CardReviewWithoutCardId>(value).unwrap();
+        let entry = ReviewLogEntry::from_without_card_id(&review, 7).unwrap();
+        assert_eq!(entry.rating, Rating::Hard);
+        assert_eq!(entry.kind, ReviewKind::Relearning);
+        assert_eq!(entry.new_interval, Interval::Seconds(600));
+        assert_eq!(CardReviewWithoutCardId::from(&entry), review);
+    }
+
+    #[test]
+    fn invalid_rating() {
+        let tuple: CardReviewTuple = (1, 2, -1, 9, 1, 1, 2500, 6000, 1);
+        assert!(matches!(ReviewLogEntry::from_tuple(&tuple), Err(Error::Invalid(_))));
+    }
+
+}
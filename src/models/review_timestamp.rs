@@ -0,0 +1,207 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::Error;
+
+/// The time a review occurred, in milliseconds from UNIX epoch.
+/// Anki uses this as the review's ID.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ReviewTimestamp(pub i64);
+
+impl ReviewTimestamp {
+
+    pub fn from_millis(millis: i64) -> Self {
+        ReviewTimestamp(millis)
+    }
+
+    pub fn as_millis(&self) -> i64 {
+        self.0
+    }
+
+    /// Whole seconds from UNIX epoch, rounded down.
+    pub fn as_secs(&self) -> i64 {
+        self.0.div_euclid(1000)
+    }
+
+    pub fn to_system_time(&self) -> SystemTime {
+        let offset = Duration::from_millis(self.0.unsigned_abs());
+        if self.0 < 0 {
+            UNIX_EPOCH - offset
+        } else {
+            UNIX_EPOCH + offset
+        }
+    }
+
+    /// Truncated to milliseconds. Times that do not fit are saturated.
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(x) => i64::try_from(x.as_millis()).unwrap_or(i64::MAX),
+            Err(e) => i64::try_from(e.duration().as_millis()).map(|x| -x).unwrap_or(i64::MIN),
+        };
+        ReviewTimestamp(millis)
+    }
+
+}
+
+impl From<i64> for ReviewTimestamp {
+    fn from(value: i64) -> Self {
+        ReviewTimestamp(value)
+    }
+}
+
+impl From<ReviewTimestamp> for i64 {
+    fn from(value: ReviewTimestamp) -> Self {
+        value.0
+    }
+}
+
+/// Formats as an RFC 3339 timestamp in UTC, e.g. `2023-11-14T22:13:20.123Z`.
+impl std::fmt::Display for ReviewTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let secs = self.as_secs();
+        let millis = self.0.rem_euclid(1000);
+        let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+        let time = secs.rem_euclid(86_400);
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{millis:03}Z",
+            time / 3600,
+            time % 3600 / 60,
+            time % 60,
+        )
+    }
+}
+
+/// Parses an RFC 3339 timestamp, e.g. `2023-11-14T22:13:20Z` or `2023-11-14 23:13:20.5+01:00`.
+/// Digits past milliseconds are truncated.
+impl std::str::FromStr for ReviewTimestamp {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::Invalid(format!("invalid timestamp {s:?}, expected RFC 3339"));
+        let number = |range: std::ops::Range<usize>| -> Result<i64, Error> {
+            let digits = s.get(range).ok_or_else(invalid)?;
+            if !digits.bytes().all(|x| x.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            digits.parse().map_err(|_| invalid())
+        };
+
+        let bytes = s.as_bytes();
+        if bytes.len() < 20
+            || bytes[4] != b'-' || bytes[7] != b'-'
+            || !matches!(bytes[10], b'T' | b't' | b' ')
+            || bytes[13] != b':' || bytes[16] != b':' {
+            return Err(invalid());
+        }
+        let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+        let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+            return Err(invalid());
+        }
+
+        let mut rest = &s[19..];
+        let mut millis = 0;
+        if let Some(fraction) = rest.strip_prefix('.') {
+            let digits = fraction.bytes().take_while(|x| x.is_ascii_digit()).count();
+            if digits == 0 {
+                return Err(invalid());
+            }
+            let padded = format!("{:0<3}", &fraction[..digits.min(3)]);
+            millis = padded.parse::<i64>().map_err(|_| invalid())?;
+            rest = &fraction[digits..];
+        }
+
+        let offset = match rest {
+            "Z" | "z" => 0,
+            _ if rest.len() == 6 && matches!(rest.as_bytes()[0], b'+' | b'-') && rest.as_bytes()[3] == b':' => {
+                let offset = number(s.len() - 5..s.len() - 3)? * 3600 + number(s.len() - 2..s.len())? * 60;
+                if rest.starts_with('-') { -offset } else { offset }
+            },
+            _ => return Err(invalid()),
+        };
+
+        // Rejects days past the end of the month, e.g. February 31
+        let days = days_from_civil(year, month as u32, day as u32);
+        if civil_from_days(days) != (year, month as u32, day as u32) {
+            return Err(invalid());
+        }
+        let secs = days * 86_400 + hour * 3600 + minute * 60 + second - offset;
+        Ok(ReviewTimestamp(secs * 1000 + millis))
+    }
+}
+
+/// Converts a proleptic Gregorian date to days from UNIX epoch.
+/// See: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+/// `month` must be 1 to 12, `day` 1 to 31, and `year` far enough from the limits of `i64` for
+/// the arithmetic not to overflow.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Converts days from UNIX epoch to a proleptic Gregorian (year, month, day).
+/// See: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_time_round_trip() {
+        for millis in [-1_500, 0, 1_700_000_000_123] {
+            let timestamp = ReviewTimestamp(millis);
+            assert_eq!(ReviewTimestamp::from_system_time(timestamp.to_system_time()), timestamp);
+        }
+    }
+
+    #[test]
+    fn secs_round_down() {
+        assert_eq!(ReviewTimestamp(1_999).as_secs(), 1);
+        assert_eq!(ReviewTimestamp(-1).as_secs(), -1);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ReviewTimestamp(0).to_string(), "1970-01-01T00:00:00.000Z");
+        assert_eq!(ReviewTimestamp(1_700_000_000_123).to_string(), "2023-11-14T22:13:20.123Z");
+        assert_eq!(ReviewTimestamp(951_782_400_000).to_string(), "2000-02-29T00:00:00.000Z");
+        assert_eq!(ReviewTimestamp(-1).to_string(), "1969-12-31T23:59:59.999Z");
+    }
+
+    #[test]
+    fn parse() {
+        for (text, millis) in [
+            ("1970-01-01T00:00:00Z", 0),
+            ("2023-11-14T22:13:20.123Z", 1_700_000_000_123),
+            ("2023-11-14 23:13:20.1234+01:00", 1_700_000_000_123),
+            ("2000-02-29T00:00:00-00:30", 951_784_200_000),
+        ] {
+            assert_eq!(text.parse::<ReviewTimestamp>().unwrap(), ReviewTimestamp(millis), "{text}");
+        }
+        for millis in [-1, 0, 951_782_400_000, 1_700_000_000_123] {
+            let timestamp = ReviewTimestamp(millis);
+            assert_eq!(timestamp.to_string().parse::<ReviewTimestamp>().unwrap(), timestamp);
+        }
+        for text in ["", "2023-11-14", "2023-13-14T22:13:20Z", "2023-11-14T22:13:20", "2023-11-14T22:13:20.Z", "2023-11-14T22:13:20+0100", "2023-02-31T00:00:00Z", "2023-02-29T00:00:00Z"] {
+            assert!(matches!(text.parse::<ReviewTimestamp>(), Err(Error::Invalid(_))), "{text}");
+        }
+    }
+
+}
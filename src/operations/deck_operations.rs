@@ -0,0 +1,313 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::Client;
+use crate::endpoints::{
+    ChangeDeck,
+    CreateDeck,
+    DeckNamesAndIds,
+    DeleteDecks,
+    FindCards,
+    GetDecks,
+};
+use crate::error::Error;
+use crate::models::deck_tree::DECK_SEPARATOR;
+
+// Deck operations
+
+/// A card that was moved by one of the deck operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CardMove {
+    pub card: u64,
+    pub from_deck: String,
+    pub to_deck: String,
+}
+
+/// What a deck operation changed.
+#[derive(Clone, Debug, Default)]
+pub struct DeckMoveReport {
+    /// Every card that changed deck
+    pub moved: Vec<CardMove>,
+    /// Decks that had to be created
+    pub created_decks: Vec<String>,
+    /// Decks that were deleted after they had been emptied
+    pub deleted_decks: Vec<String>,
+}
+
+/// Renames the deck `from` to `to`, along with all of its subdecks.
+/// Anki-Connect has no rename action, so this creates the new decks, moves every card into the
+/// equivalent subdeck, and deletes each old deck, subdecks first, after checking that it is
+/// still empty. If cards or subdecks appear in an old deck in the meantime, it is left in place
+/// and an error is returned.
+/// Fails before changing anything if `from` does not exist or `to` already exists.
+pub async fn rename_deck(client: &Client<'_>, from: &str, to: &str) -> Result<DeckMoveReport, Error> {
+    let decks = client.invoke_default::<DeckNamesAndIds>().await?;
+    if find_deck(&decks, to).is_some() {
+        return Err(Error::Invalid(format!("deck {to:?} already exists")));
+    }
+    move_subtree(client, &decks, from, to).await
+}
+
+/// Merges the deck `from` and its subdecks into `to`.
+/// Each card keeps its path relative to `from`, so a card in `from::Verbs` ends up in
+/// `to::Verbs`. Subdecks that do not exist in `to` yet are created. `from` is deleted once it is
+/// confirmed to be empty.
+pub async fn merge_deck(client: &Client<'_>, from: &str, to: &str) -> Result<DeckMoveReport, Error> {
+    let decks = client.invoke_default::<DeckNamesAndIds>().await?;
+    move_subtree(client, &decks, from, to).await
+}
+
+/// Moves every card matching `query` into `deck`, creating the deck if necessary.
+/// See: <https://docs.ankiweb.net/searching.html>
+pub async fn move_cards(client: &Client<'_>, query: &str, deck: &str) -> Result<DeckMoveReport, Error> {
+    let decks = client.invoke_default::<DeckNamesAndIds>().await?;
+    let cards = client.invoke(&FindCards::new(query)).await?;
+
+    let mut report = DeckMoveReport::default();
+
+    let deck = canonical_deck_name(&decks, deck);
+    let deck = deck.as_str();
+    if find_deck(&decks, deck).is_none() {
+        client.invoke(&CreateDeck::new(deck)).await?;
+        report.created_decks.push(deck.to_string());
+    }
+
+    let current = client.invoke(&GetDecks::new(&cards)).await?;
+
+    client.invoke(&ChangeDeck::new(&cards, deck)).await?;
+
+    for (from_deck, cards) in current {
+        if from_deck == deck {
+            continue;
+        }
+        report.moved.extend(cards.into_iter().map(|card| CardMove {
+            card,
+            from_deck: from_deck.clone(),
+            to_deck: deck.to_string(),
+        }));
+    }
+
+    report.moved.sort_by_key(|x| x.card);
+    Ok(report)
+}
+
+async fn move_subtree(
+    client: &Client<'_>,
+    decks: &HashMap<String, u64>,
+    from: &str,
+    to: &str,
+) -> Result<DeckMoveReport, Error> {
+
+    let renames = plan_subtree_move(decks, from, to)?;
+
+    let source_ids: Vec<u64> = renames.keys().map(|x| decks[x]).collect();
+    let cards = find_cards_in_decks(client, &source_ids).await?;
+    let current = client.invoke(&GetDecks::new(&cards)).await?;
+
+    let mut report = DeckMoveReport::default();
+
+    // Parents sort before their children, so every deck is created in order
+    for target in renames.values() {
+        if find_deck(decks, target).is_none() {
+            client.invoke(&CreateDeck::new(target.as_str())).await?;
+            report.created_decks.push(target.clone());
+        }
+    }
+
+    let mut expected: HashMap<u64, &str> = HashMap::new();
+
+    for (source, cards) in &current {
+        let target = renames.get(source).ok_or_else(|| Error::Operation(format!(
+            "card search for {from:?} returned cards in unrelated deck {source:?}",
+        )))?;
+        client.invoke(&ChangeDeck::new(cards, target.as_str())).await?;
+        for &card in cards {
+            expected.insert(card, target);
+            report.moved.push(CardMove {
+                card,
+                from_deck: source.clone(),
+                to_deck: target.clone(),
+            });
+        }
+    }
+
+    // Check every card arrived and nothing is left behind before deleting anything
+    let arrived = client.invoke(&GetDecks::new(&cards)).await?;
+    let arrived_count: usize = arrived.values().map(Vec::len).sum();
+    if arrived_count != cards.len() {
+        return Err(Error::Operation(format!(
+            "expected {} cards after moving, found {arrived_count}; {from:?} was not deleted",
+            cards.len(),
+        )));
+    }
+    for (deck, cards) in &arrived {
+        // Deck names are case-insensitive in Anki
+        let misplaced = cards.iter().find(|x| expected.get(x).is_none_or(|x| x.to_lowercase() != deck.to_lowercase()));
+        if let Some(card) = misplaced {
+            return Err(Error::Operation(format!(
+                "card {card} is in {deck:?} after moving; {from:?} was not deleted",
+            )));
+        }
+    }
+
+    // Anki deletes subdecks and cards along with a deck, so only delete decks that are known and
+    // empty, children first
+    let after = client.invoke_default::<DeckNamesAndIds>().await?;
+    let unexpected = after.keys().find(|x| is_in_subtree(x, from) && !renames.contains_key(*x));
+    if let Some(deck) = unexpected {
+        return Err(Error::Operation(format!(
+            "deck {deck:?} was created while moving; {from:?} was not deleted",
+        )));
+    }
+    for source in renames.keys().rev() {
+        let remaining = find_cards_in_decks(client, &[decks[source]]).await?;
+        if !remaining.is_empty() {
+            return Err(Error::Operation(format!(
+                "{} cards are still in {source:?}; it was not deleted",
+                remaining.len(),
+            )));
+        }
+        client.invoke(&DeleteDecks::single(source.as_str()).with_cards_too(true)).await?;
+        report.deleted_decks.push(source.clone());
+    }
+
+    report.moved.sort_by_key(|x| x.card);
+    Ok(report)
+}
+
+async fn find_cards_in_decks(client: &Client<'_>, decks: &[u64]) -> Result<Vec<u64>, Error> {
+    let mut cards = HashSet::new();
+    for deck in decks {
+        let query = format!("did:{deck}");
+        cards.extend(client.invoke(&FindCards::new(query)).await?);
+    }
+    let mut cards: Vec<u64> = cards.into_iter().collect();
+    cards.sort_unstable();
+    Ok(cards)
+}
+
+/// Deck names are case-insensitive in Anki
+fn find_deck<'a>(decks: &'a HashMap<String, u64>, name: &str) -> Option<&'a str> {
+    decks
+        .keys()
+        .find(|x| x.to_lowercase() == name.to_lowercase())
+        .map(String::as_str)
+}
+
+fn is_in_subtree(name: &str, root: &str) -> bool {
+    let name = name.to_lowercase();
+    let root = root.to_lowercase();
+    name == root || name.starts_with(&format!("{root}{DECK_SEPARATOR}"))
+}
+
+/// Maps the name of each deck in the `from` subtree to its name under `to`.
+fn plan_subtree_move(
+    decks: &HashMap<String, u64>,
+    from: &str,
+    to: &str,
+) -> Result<BTreeMap<String, String>, Error> {
+
+    let from = find_deck(decks, from)
+        .ok_or_else(|| Error::Invalid(format!("deck {from:?} does not exist")))?;
+
+    if is_in_subtree(to, from) {
+        return Err(Error::Invalid(format!("cannot move {from:?} into itself ({to:?})")));
+    }
+
+    if to.is_empty() || to.split(DECK_SEPARATOR).any(|x| x.trim().is_empty()) {
+        return Err(Error::Invalid(format!("invalid deck name {to:?}")));
+    }
+
+    // Anki keeps subdeck names in sync with their parent, so an exact prefix match is enough
+    let prefix = format!("{from}{DECK_SEPARATOR}");
+
+    Ok(decks
+        .keys()
+        .filter_map(|x| match x.strip_prefix(&prefix) {
+            Some(rest) => Some((x.clone(), format!("{to}{DECK_SEPARATOR}{rest}"))),
+            None if x == from => Some((x.clone(), to.to_string())),
+            None => None,
+        })
+        .map(|(source, target)| (source, canonical_deck_name(decks, &target)))
+        .collect())
+}
+
+/// Spells each level of `name` the way an existing deck already spells it, as Anki does when it
+/// creates or moves into a deck.
+fn canonical_deck_name(decks: &HashMap<String, u64>, name: &str) -> String {
+    let mut result = String::new();
+    for part in name.split(DECK_SEPARATOR) {
+        let candidate = if result.is_empty() {
+            part.to_string()
+        } else {
+            format!("{result}{DECK_SEPARATOR}{part}")
+        };
+        result = find_deck(decks, &candidate).map_or(candidate, str::to_string);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> HashMap<String, u64> {
+        HashMap::from([
+            ("Default".to_string(), 1),
+            ("Lang".to_string(), 2),
+            ("Lang::Japanese".to_string(), 3),
+            ("Lang::Japanese::Vocab".to_string(), 4),
+            ("Languages".to_string(), 5),
+        ])
+    }
+
+    #[test]
+    fn plan_keeps_relative_paths() {
+        let plan = plan_subtree_move(&example(), "lang::japanese", "JP").unwrap();
+        assert_eq!(plan, BTreeMap::from([
+            ("Lang::Japanese".to_string(), "JP".to_string()),
+            ("Lang::Japanese::Vocab".to_string(), "JP::Vocab".to_string()),
+        ]));
+    }
+
+    #[test]
+    fn plan_does_not_match_prefix_of_other_deck() {
+        let plan = plan_subtree_move(&example(), "Lang", "Old::Lang").unwrap();
+        assert!(!plan.contains_key("Languages"));
+        assert_eq!(plan.len(), 3);
+    }
+
+    #[test]
+    fn plan_rejects_missing_source() {
+        assert!(plan_subtree_move(&example(), "French", "Lang::French").is_err());
+    }
+
+    #[test]
+    fn plan_rejects_moving_into_itself() {
+        assert!(plan_subtree_move(&example(), "Lang", "Lang::Old").is_err());
+        assert!(plan_subtree_move(&example(), "Lang", "lang").is_err());
+    }
+
+    #[test]
+    fn plan_rejects_empty_components() {
+        assert!(plan_subtree_move(&example(), "Lang", "").is_err());
+        assert!(plan_subtree_move(&example(), "Lang", "Old::").is_err());
+    }
+
+    #[test]
+    fn plan_uses_existing_spelling_of_target() {
+        let mut decks = example();
+        decks.insert("Spanish".to_string(), 6);
+        decks.insert("Spanish::Verbs".to_string(), 7);
+        decks.insert("Lang::Spanish".to_string(), 8);
+        decks.insert("Lang::Spanish::verbs".to_string(), 9);
+        decks.insert("Lang::Spanish::Nouns".to_string(), 10);
+
+        let plan = plan_subtree_move(&decks, "Lang::Spanish", "spanish").unwrap();
+        assert_eq!(plan, BTreeMap::from([
+            ("Lang::Spanish".to_string(), "Spanish".to_string()),
+            ("Lang::Spanish::verbs".to_string(), "Spanish::Verbs".to_string()),
+            ("Lang::Spanish::Nouns".to_string(), "Spanish::Nouns".to_string()),
+        ]));
+    }
+
+}
@@ -0,0 +1,151 @@
+use std::io::Write;
+
+use crate::error::Error;
+use crate::models::{CardReview, Interval, Rating, ReviewKind, ReviewLogEntry};
+
+// FSRS export operations
+
+/// The state of a card before a review, as the FSRS optimizer numbers them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FsrsState {
+    New = 0,
+    Learning = 1,
+    Review = 2,
+    Relearning = 3,
+    /// Manual and rescheduled entries, which the optimizer ignores
+    Manual = 4,
+}
+
+/// A row of the FSRS optimizer's review log input.
+/// See: https://github.com/open-spaced-repetition/fsrs-optimizer
+#[derive(Clone, Debug, PartialEq)]
+pub struct FsrsReview {
+    pub card_id: i64,
+    /// In milliseconds from UNIX epoch
+    pub review_time: i64,
+    /// 1 to 4, or 0 for manual entries
+    pub review_rating: u8,
+    pub review_state: FsrsState,
+    /// In milliseconds
+    pub review_duration: u32,
+}
+
+/// Converts reviews, e.g. from `cardReviews` or [`crate::operations::fetch_reviews_of_cards`], to
+/// the FSRS optimizer's format, ordered by card and then by time.
+///
+/// A card's first learning review is in the `New` state, as is its first learning review after it
+/// was reset. Reviews in filtered decks count as `Review`. Manual and rescheduled entries are
+/// dropped unless `include_manual` is set.
+/// Fails if a review has a rating or kind Anki does not know.
+pub fn fsrs_reviews(reviews: &[CardReview], include_manual: bool) -> Result<Vec<FsrsReview>, Error> {
+    let mut entries = reviews
+        .iter()
+        .map(ReviewLogEntry::try_from)
+        .collect::<Result<Vec<_>, Error>>()?;
+    entries.sort_by_key(|x| (x.card_id, x.timestamp));
+
+    let mut result = Vec::with_capacity(entries.len());
+    let mut previous: Option<&ReviewLogEntry> = None;
+
+    for entry in &entries {
+        let new = match previous {
+            Some(x) if x.card_id == entry.card_id => is_reset(x),
+            _ => true,
+        };
+        previous = Some(entry);
+
+        let review_state = match entry.kind {
+            _ if entry.rating == Rating::Manual => FsrsState::Manual,
+            ReviewKind::Learning if new => FsrsState::New,
+            ReviewKind::Learning => FsrsState::Learning,
+            ReviewKind::Review | ReviewKind::Filtered => FsrsState::Review,
+            ReviewKind::Relearning => FsrsState::Relearning,
+            ReviewKind::Manual | ReviewKind::Rescheduled => FsrsState::Manual,
+        };
+        if review_state == FsrsState::Manual && !include_manual {
+            continue;
+        }
+
+        result.push(FsrsReview {
+            card_id: entry.card_id,
+            review_time: entry.timestamp.as_millis(),
+            review_rating: entry.rating.as_u8(),
+            review_state,
+            review_duration: entry.review_duration,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Writes reviews as CSV with the header the FSRS optimizer expects.
+pub fn write_fsrs_csv(mut writer: impl Write, reviews: &[FsrsReview]) -> Result<(), Error> {
+    writeln!(writer, "card_id,review_time,review_rating,review_state,review_duration").map_err(Error::Io)?;
+    for review in reviews {
+        writeln!(
+            writer,
+            "{},{},{},{},{}",
+            review.card_id,
+            review.review_time,
+            review.review_rating,
+            review.review_state as u8,
+            review.review_duration,
+        ).map_err(Error::Io)?;
+    }
+    writer.flush().map_err(Error::Io)
+}
+
+/// Anki records resetting a card ("Forget") as a manual entry that leaves it without an interval.
+fn is_reset(entry: &ReviewLogEntry) -> bool {
+    entry.kind == ReviewKind::Manual && entry.new_interval == Interval::Days(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn states_and_order() {
+        let reviews: Vec<CardReview> = [
+            (5_000, 2, -1, 3, 1, -600, 2500, 3000, 0),
+            (1_000, 1, -1, 3, -600, 0, 0, 4000, 0),
+            (2_000, 1, -1, 3, 1, -600, 2500, 5000, 0),
+            (3_000, 1, -1, 1, -600, 1, 2300, 6000, 1),
+            (4_000, 1, -1, 3, 2, -600, 2300, 7000, 2),
+            (4_500, 1, -1, 0, 0, 2, 0, 0, 4),
+            (6_000, 1, -1, 3, -600, 0, 0, 8000, 0),
+            (7_000, 1, -1, 4, 3, -600, 2500, 9000, 3),
+        ].iter().map(CardReview::from_tuple).collect();
+
+        let result = fsrs_reviews(&reviews, false).unwrap();
+        let rows: Vec<(i64, i64, FsrsState)> = result
+            .iter()
+            .map(|x| (x.card_id, x.review_time, x.review_state))
+            .collect();
+        assert_eq!(rows, vec![
+            (1, 1_000, FsrsState::New),
+            (1, 2_000, FsrsState::Learning),
+            (1, 3_000, FsrsState::Review),
+            (1, 4_000, FsrsState::Relearning),
+            (1, 6_000, FsrsState::New),
+            (1, 7_000, FsrsState::Review),
+            (2, 5_000, FsrsState::New),
+        ]);
+
+        let result = fsrs_reviews(&reviews, true).unwrap();
+        assert_eq!(result.len(), 8);
+        assert_eq!(result[4].review_state, FsrsState::Manual);
+    }
+
+    #[test]
+    fn csv() {
+        let reviews = [CardReview::from_tuple(&(1_000, 1, -1, 3, -600, 0, 0, 4000, 0))];
+        let mut buffer = Vec::new();
+        write_fsrs_csv(&mut buffer, &fsrs_reviews(&reviews, false).unwrap()).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "\
+card_id,review_time,review_rating,review_state,review_duration
+1,1000,3,0,4000
+");
+    }
+
+}
@@ -0,0 +1,23 @@
+mod deck_operations;
+mod fsrs_export;
+mod model_export;
+mod model_operations;
+mod profile_operations;
+mod review_analytics;
+mod review_export;
+mod review_import;
+mod review_log_cursor;
+mod review_operations;
+mod statistic_operations;
+
+pub use deck_operations::*;
+pub use fsrs_export::*;
+pub use model_export::*;
+pub use model_operations::*;
+pub use profile_operations::*;
+pub use review_analytics::*;
+pub use review_export::*;
+pub use review_import::*;
+pub use review_log_cursor::*;
+pub use review_operations::*;
+pub use statistic_operations::*;
@@ -0,0 +1,377 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::Client;
+use crate::endpoints::{FindModelsByName, ModelNames, ModelStyling, find_duplicate};
+use crate::error::Error;
+use crate::models::{CardTemplate, ModelInfo, ModelSpec};
+
+// Exporting models to files
+//
+// Each model is written to its own directory:
+//
+//     <model>/model.json          name, cloze flag and template order
+//     <model>/fields.json         field names, fonts and descriptions, in order
+//     <model>/style.css
+//     <model>/<template>/front.html
+//     <model>/<template>/back.html
+//
+// Directory names are the model and template names, with characters that are not allowed in file
+// names replaced by `_`. The real names are kept in `model.json`.
+
+const MODEL_FILE: &str = "model.json";
+const FIELDS_FILE: &str = "fields.json";
+const STYLE_FILE: &str = "style.css";
+const FRONT_FILE: &str = "front.html";
+const BACK_FILE: &str = "back.html";
+
+/// A model as stored in an export directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportedModel {
+    /// Name, fields, templates and CSS. Can be passed to `ModelPlan` to restore the model.
+    pub spec: ModelSpec,
+    /// Editor settings of each field, in the same order as `spec.fields`
+    pub fields: Vec<ExportedField>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExportedField {
+    pub name: String,
+    pub font: String,
+    pub size: u32,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ModelFile {
+    name: String,
+    #[serde(rename = "isCloze")]
+    is_cloze: bool,
+    templates: Vec<TemplateEntry>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TemplateEntry {
+    name: String,
+    directory: String,
+}
+
+impl ExportedModel {
+
+    fn from_model_info(model: &ModelInfo, css: String) -> Self {
+        let mut spec = ModelSpec::new(model.name.as_str())
+            .css(css)
+            .cloze(model.is_cloze());
+        for x in &model.fields {
+            spec = spec.field(x.name.as_str());
+        }
+        for x in &model.templates {
+            spec = spec.template(x.name.as_str(), CardTemplate::new(x.front.as_str(), x.back.as_str()));
+        }
+        let fields = model.fields
+            .iter()
+            .map(|x| ExportedField {
+                name: x.name.clone(),
+                font: x.font.clone(),
+                size: x.size,
+                description: x.description.clone(),
+            })
+            .collect();
+        Self {
+            spec,
+            fields,
+        }
+    }
+
+    /// Write the model into `dir`, which is created if necessary.
+    /// Existing files are overwritten. Directories of templates that no longer exist are left in
+    /// place, but are not listed in `model.json` and so are ignored when reading.
+    pub fn write(&self, dir: &Path) -> Result<(), Error> {
+
+        self.validate()?;
+
+        // Template directories share `dir` with the model files
+        let mut used: std::collections::HashSet<String> = [MODEL_FILE, FIELDS_FILE, STYLE_FILE]
+            .iter()
+            .map(|x| x.to_lowercase())
+            .collect();
+        let templates: Vec<TemplateEntry> = self.spec.templates
+            .iter()
+            .map(|x| TemplateEntry {
+                name: x.name.clone(),
+                directory: unique_file_name(&x.name, &mut used),
+            })
+            .collect();
+
+        std::fs::create_dir_all(dir).map_err(Error::Io)?;
+
+        let model_file = ModelFile {
+            name: self.spec.name.clone(),
+            is_cloze: self.spec.is_cloze,
+            templates,
+        };
+        write_json(&dir.join(MODEL_FILE), &model_file)?;
+        write_json(&dir.join(FIELDS_FILE), &self.fields)?;
+        std::fs::write(dir.join(STYLE_FILE), &self.spec.css).map_err(Error::Io)?;
+
+        for (entry, template) in model_file.templates.iter().zip(&self.spec.templates) {
+            let template_dir = template_dir(dir, &entry.directory)?;
+            std::fs::create_dir_all(&template_dir).map_err(Error::Io)?;
+            std::fs::write(template_dir.join(FRONT_FILE), &template.template.front).map_err(Error::Io)?;
+            std::fs::write(template_dir.join(BACK_FILE), &template.template.back).map_err(Error::Io)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read a model previously written with `write`.
+    pub fn read(dir: &Path) -> Result<Self, Error> {
+
+        let model_file: ModelFile = read_json(&dir.join(MODEL_FILE))?;
+        let fields: Vec<ExportedField> = read_json(&dir.join(FIELDS_FILE))?;
+        let css = std::fs::read_to_string(dir.join(STYLE_FILE)).map_err(Error::Io)?;
+
+        let mut spec = ModelSpec::new(model_file.name)
+            .css(css)
+            .cloze(model_file.is_cloze);
+
+        for x in &fields {
+            spec = spec.field(x.name.as_str());
+        }
+
+        for x in model_file.templates {
+            let template_dir = template_dir(dir, &x.directory)?;
+            let front = std::fs::read_to_string(template_dir.join(FRONT_FILE)).map_err(Error::Io)?;
+            let back = std::fs::read_to_string(template_dir.join(BACK_FILE)).map_err(Error::Io)?;
+            spec = spec.template(x.name, CardTemplate::new(front, back));
+        }
+
+        let model = Self {
+            spec,
+            fields,
+        };
+        model.validate()?;
+        Ok(model)
+    }
+
+    /// Checks that `fields` matches the fields of `spec`, and that no field or template name is
+    /// used twice.
+    fn validate(&self) -> Result<(), Error> {
+        let name = &self.spec.name;
+        if !self.fields.iter().map(|x| &x.name).eq(self.spec.fields.iter().map(|x| &x.name)) {
+            return Err(Error::Invalid(format!("fields of model {name:?} do not match its spec")));
+        }
+        if let Some(x) = find_duplicate(self.spec.field_names()) {
+            return Err(Error::Invalid(format!("model {name:?} has more than one field named {x:?}")));
+        }
+        if let Some(x) = find_duplicate(self.spec.templates.iter().map(|x| x.name.as_str())) {
+            return Err(Error::Invalid(format!("model {name:?} has more than one template named {x:?}")));
+        }
+        Ok(())
+    }
+
+}
+
+/// Export every model into its own directory under `dir`.
+/// Returns the directory written for each model.
+pub async fn export_models(client: &Client<'_>, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let model_names = client.invoke_default::<ModelNames>().await?;
+    let models = fetch_models(client, &model_names).await?;
+
+    let mut used = std::collections::HashSet::new();
+    let mut written = Vec::new();
+    for model in models {
+        let model_dir = dir.join(unique_file_name(&model.spec.name, &mut used));
+        model.write(&model_dir)?;
+        written.push(model_dir);
+    }
+    Ok(written)
+}
+
+/// Export a single model into `dir`.
+pub async fn export_model(client: &Client<'_>, model_name: &str, dir: &Path) -> Result<(), Error> {
+    let models = fetch_models(client, &[model_name.to_string()]).await?;
+    match models.first() {
+        Some(x) => x.write(dir),
+        None => Err(Error::Invalid(format!("model {model_name:?} does not exist"))),
+    }
+}
+
+/// Read every model directory under `dir`, as written by `export_models`.
+/// Subdirectories without a `model.json` are skipped. Models are sorted by name.
+pub fn read_exported_models(dir: &Path) -> Result<Vec<ExportedModel>, Error> {
+    let mut models = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(Error::Io)? {
+        let path = entry.map_err(Error::Io)?.path();
+        if path.join(MODEL_FILE).is_file() {
+            models.push(ExportedModel::read(&path)?);
+        }
+    }
+    models.sort_by(|a, b| a.spec.name.cmp(&b.spec.name));
+    Ok(models)
+}
+
+async fn fetch_models(client: &Client<'_>, model_names: &[String]) -> Result<Vec<ExportedModel>, Error> {
+    let request = FindModelsByName::new(
+        model_names.iter().map(|x| x.as_str().into()).collect::<Vec<_>>()
+    );
+    let models = client.invoke(&request).await?;
+
+    let mut exported = Vec::new();
+    for model in models {
+        let styling = client.invoke(&ModelStyling::new(model.name.as_str())).await?;
+        exported.push(ExportedModel::from_model_info(&model, styling.css));
+    }
+    Ok(exported)
+}
+
+fn write_json<T: serde::Serialize>(path: &Path, value: &T) -> Result<(), Error> {
+    let mut text = serde_json::to_string_pretty(value).map_err(Error::SerializeSerde)?;
+    text.push('\n');
+    std::fs::write(path, text).map_err(Error::Io)
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, Error> {
+    let text = std::fs::read_to_string(path).map_err(Error::Io)?;
+    serde_json::from_str(&text).map_err(Error::DeserializeSerde)
+}
+
+/// The directory of a template within the model directory `dir`.
+/// `directory` comes from `model.json`, so anything but a plain name, such as `..` or an absolute
+/// path, is rejected.
+fn template_dir(dir: &Path, directory: &str) -> Result<PathBuf, Error> {
+    let mut components = Path::new(directory).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => Ok(dir.join(directory)),
+        _ => Err(Error::Invalid(format!("invalid template directory {directory:?}"))),
+    }
+}
+
+/// Replaces characters that are not allowed in file names on common platforms, and appends a
+/// number if the result has been used already (names differing only by case included).
+fn unique_file_name(name: &str, used: &mut std::collections::HashSet<String>) -> String {
+    let mut base: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    let trimmed = base.trim_end_matches(['.', ' ']);
+    if trimmed.is_empty() {
+        base = "_".to_string();
+    } else {
+        base.truncate(trimmed.len());
+    }
+
+    let mut candidate = base.clone();
+    let mut n = 2;
+    while !used.insert(candidate.to_lowercase()) {
+        candidate = format!("{base} ({n})");
+        n += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_names() {
+        let mut used = std::collections::HashSet::new();
+        assert_eq!(unique_file_name("Basic", &mut used), "Basic");
+        assert_eq!(unique_file_name("basic", &mut used), "basic (2)");
+        assert_eq!(unique_file_name("Q/A: \"Vocab\"", &mut used), "Q_A_ _Vocab_");
+        assert_eq!(unique_file_name("..", &mut used), "_");
+    }
+
+    #[test]
+    fn template_directories_stay_inside() {
+        let dir = Path::new("export").join("Vocab");
+        assert_eq!(template_dir(&dir, "Card 1").unwrap(), dir.join("Card 1"));
+        for directory in ["..", "../..", "../Other", "Card/1", "/etc", "", "."] {
+            assert!(template_dir(&dir, directory).is_err(), "{directory:?} was accepted");
+        }
+    }
+
+    #[test]
+    fn fields_must_match_spec() {
+        let model = ExportedModel {
+            spec: ModelSpec::new("Vocab").field("Word").field("Meaning"),
+            fields: vec![ExportedField {
+                name: "Word".to_string(),
+                font: "Arial".to_string(),
+                size: 20,
+                description: "".to_string(),
+            }],
+        };
+        let dir = std::env::temp_dir().join(format!("ankiconnect-model-export-fields-{}", std::process::id()));
+        assert!(matches!(model.write(&dir), Err(Error::Invalid(_))));
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn template_named_like_model_file() {
+        let model = ExportedModel {
+            spec: ModelSpec::new("Vocab")
+                .field("Word")
+                .template("model.json", CardTemplate::new("{{Word}}", "{{Word}}"))
+                .template("Style.css", CardTemplate::new("{{Word}}", "{{Word}}")),
+            fields: vec![ExportedField {
+                name: "Word".to_string(),
+                font: "Arial".to_string(),
+                size: 20,
+                description: "".to_string(),
+            }],
+        };
+
+        let dir = std::env::temp_dir().join(format!("ankiconnect-model-export-names-{}", std::process::id()));
+        model.write(&dir).unwrap();
+
+        assert!(dir.join(MODEL_FILE).is_file());
+        assert!(dir.join("model.json (2)").join(FRONT_FILE).is_file());
+        assert!(dir.join("Style.css (2)").join(FRONT_FILE).is_file());
+        assert_eq!(ExportedModel::read(&dir).unwrap(), model);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn write_and_read() {
+        let model = ExportedModel {
+            spec: ModelSpec::new("Vocab/Reading")
+                .field("Word")
+                .field("Meaning")
+                .template("Card: 1", CardTemplate::new("{{Word}}", "{{FrontSide}}<hr>{{Meaning}}"))
+                .template("Card 2", CardTemplate::new("{{Meaning}}", "{{Word}}"))
+                .css(".card { color: black; }\n"),
+            fields: vec![
+                ExportedField {
+                    name: "Word".to_string(),
+                    font: "Arial".to_string(),
+                    size: 20,
+                    description: "".to_string(),
+                },
+                ExportedField {
+                    name: "Meaning".to_string(),
+                    font: "Noto Sans".to_string(),
+                    size: 16,
+                    description: "English".to_string(),
+                },
+            ],
+        };
+
+        let dir = std::env::temp_dir().join(format!("ankiconnect-model-export-{}", std::process::id()));
+        let model_dir = dir.join("Vocab_Reading");
+        model.write(&model_dir).unwrap();
+
+        assert!(model_dir.join("Card_ 1").join(FRONT_FILE).is_file());
+        assert_eq!(ExportedModel::read(&model_dir).unwrap(), model);
+        assert_eq!(read_exported_models(&dir).unwrap(), vec![model]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+}
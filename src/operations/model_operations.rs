@@ -0,0 +1,653 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use crate::Client;
+use crate::endpoints::{
+    CreateModel,
+    FindModelsByName,
+    ModelFieldAdd,
+    ModelFieldRemove,
+    ModelFieldRename,
+    ModelFieldReposition,
+    ModelNames,
+    ModelTemplateAdd,
+    ModelTemplateRemove,
+    ModelTemplateRename,
+    ModelTemplateReposition,
+    UpdateModelStyling,
+    UpdateModelTemplates,
+    find_duplicate,
+};
+use crate::error::Error;
+use crate::models::{CardTemplate, ModelInfo, ModelSpec};
+
+// Model operations
+
+/// A single change to a model, as part of a `ModelPlan`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModelChange {
+    /// The model does not exist yet and will be created from the spec
+    CreateModel(ModelSpec),
+    RenameField { from: String, to: String },
+    /// Deletes the field's contents from every note
+    RemoveField { name: String },
+    AddField { name: String, index: usize },
+    RepositionField { name: String, index: usize },
+    RenameTemplate { from: String, to: String },
+    /// Deletes every card generated from the template
+    RemoveTemplate { name: String },
+    /// Templates are always added at the end, and repositioned afterwards if necessary
+    AddTemplate { name: String, template: CardTemplate },
+    RepositionTemplate { name: String, index: usize },
+    UpdateTemplates { templates: HashMap<String, CardTemplate> },
+    UpdateStyling { css: String },
+}
+
+impl ModelChange {
+
+    /// `true` if applying this change deletes data from the collection.
+    pub fn is_removal(&self) -> bool {
+        matches!(self, ModelChange::RemoveField { .. } | ModelChange::RemoveTemplate { .. })
+    }
+
+    async fn apply(&self, client: &Client<'_>, model_name: &str) -> Result<(), Error> {
+        match self {
+            ModelChange::CreateModel(spec) => {
+                let mut builder = CreateModel::builder(spec.name.as_str())
+                    .css(spec.css.as_str())
+                    .cloze(spec.is_cloze);
+                for x in &spec.fields {
+                    builder = builder.field(x.name.as_str());
+                }
+                for x in &spec.templates {
+                    builder = builder.template(x.name.as_str(), x.template.clone());
+                }
+                client.invoke(&builder.build()?).await?;
+            }
+            ModelChange::RenameField { from, to } => {
+                client.invoke(&ModelFieldRename::new(model_name, from, to)).await?;
+            }
+            ModelChange::RemoveField { name } => {
+                client.invoke(&ModelFieldRemove::new(model_name, name)).await?;
+            }
+            ModelChange::AddField { name, index } => {
+                client.invoke(&ModelFieldAdd::new(model_name, name, *index)).await?;
+            }
+            ModelChange::RepositionField { name, index } => {
+                client.invoke(&ModelFieldReposition::new(model_name, name, *index)).await?;
+            }
+            ModelChange::RenameTemplate { from, to } => {
+                client.invoke(&ModelTemplateRename::new(model_name, from, to)).await?;
+            }
+            ModelChange::RemoveTemplate { name } => {
+                client.invoke(&ModelTemplateRemove::new(model_name, name)).await?;
+            }
+            ModelChange::AddTemplate { name, template } => {
+                client.invoke(&ModelTemplateAdd::new(model_name, name, template.clone())).await?;
+            }
+            ModelChange::RepositionTemplate { name, index } => {
+                client.invoke(&ModelTemplateReposition::new(model_name, name, *index)).await?;
+            }
+            ModelChange::UpdateTemplates { templates } => {
+                let templates = templates
+                    .iter()
+                    .map(|(name, x)| (Cow::Borrowed(name.as_str()), Cow::Borrowed(x)))
+                    .collect();
+                client.invoke(&UpdateModelTemplates::new(model_name, templates)).await?;
+            }
+            ModelChange::UpdateStyling { css } => {
+                client.invoke(&UpdateModelStyling::new(model_name, css)).await?;
+            }
+        }
+        Ok(())
+    }
+
+}
+
+impl std::fmt::Display for ModelChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ModelChange::CreateModel(spec) => write!(
+                f,
+                "create model with {} fields and {} templates",
+                spec.fields.len(),
+                spec.templates.len(),
+            ),
+            ModelChange::RenameField { from, to } => write!(f, "rename field {from:?} to {to:?}"),
+            ModelChange::RemoveField { name } => {
+                write!(f, "remove field {name:?} (deletes its contents from every note)")
+            }
+            ModelChange::AddField { name, index } => write!(f, "add field {name:?} at position {index}"),
+            ModelChange::RepositionField { name, index } => {
+                write!(f, "move field {name:?} to position {index}")
+            }
+            ModelChange::RenameTemplate { from, to } => write!(f, "rename template {from:?} to {to:?}"),
+            ModelChange::RemoveTemplate { name } => {
+                write!(f, "remove template {name:?} (deletes every card generated from it)")
+            }
+            ModelChange::AddTemplate { name, .. } => write!(f, "add template {name:?}"),
+            ModelChange::RepositionTemplate { name, index } => {
+                write!(f, "move template {name:?} to position {index}")
+            }
+            ModelChange::UpdateTemplates { templates } => {
+                let mut names: Vec<&str> = templates.keys().map(String::as_str).collect();
+                names.sort_unstable();
+                write!(f, "update templates {names:?}")
+            }
+            ModelChange::UpdateStyling { .. } => write!(f, "update styling"),
+        }
+    }
+}
+
+/// An ordered list of changes that brings a model in Anki in line with a `ModelSpec`.
+///
+/// Fields and templates are matched by name. To rename rather than remove and re-add, list the
+/// old names in `FieldSpec::renamed_from` or `TemplateSpec::renamed_from`.
+///
+/// Display the plan for a dry run, then apply it:
+///
+/// ```no_run
+/// # async fn run() -> Result<(), ankiconnect::Error> {
+/// use ankiconnect::Client;
+/// use ankiconnect::models::{CardTemplate, ModelSpec};
+/// use ankiconnect::operations::ModelPlan;
+///
+/// let client = Client::default();
+///
+/// let spec = ModelSpec::new("Vocab")
+///     .field("Word")
+///     .field("Meaning")
+///     .template("Recognition", CardTemplate::new("{{Word}}", "{{FrontSide}}<hr>{{Meaning}}"));
+///
+/// let plan = ModelPlan::for_spec(&client, &spec).await?;
+/// println!("{plan}");
+/// plan.apply(&client).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModelPlan {
+    model_name: String,
+    steps: Vec<ModelChange>,
+}
+
+impl ModelPlan {
+
+    /// Read the model named in `spec` from Anki and plan the changes needed to match `spec`.
+    pub async fn for_spec(client: &Client<'_>, spec: &ModelSpec) -> Result<Self, Error> {
+        let model_names = client.invoke_default::<ModelNames>().await?;
+        if !model_names.contains(&spec.name) {
+            return Self::diff(spec, None);
+        }
+        let request = FindModelsByName::single(spec.name.as_str());
+        let live = client.invoke(&request).await?;
+        Self::diff(spec, live.first())
+    }
+
+    /// Plan the changes needed to turn `live` into `spec`.
+    /// `None` means the model does not exist yet.
+    /// Fails if the spec repeats a field or template name, ignoring case, or if the spec and the
+    /// live model disagree on whether the model is a cloze model, which cannot be changed after
+    /// creation.
+    pub fn diff(spec: &ModelSpec, live: Option<&ModelInfo>) -> Result<Self, Error> {
+
+        check_unique_names(&spec.name, "field", spec.fields.iter().map(|x| x.name.as_str()))?;
+        check_unique_names(&spec.name, "template", spec.templates.iter().map(|x| x.name.as_str()))?;
+
+        let mut plan = Self {
+            model_name: spec.name.clone(),
+            steps: Vec::new(),
+        };
+
+        let live = match live {
+            Some(x) => x,
+            None => {
+                plan.steps.push(ModelChange::CreateModel(spec.clone()));
+                return Ok(plan);
+            }
+        };
+
+        if live.is_cloze() != spec.is_cloze {
+            return Err(Error::Invalid(format!(
+                "model {:?} cannot be changed between cloze and standard",
+                spec.name,
+            )));
+        }
+
+        // Fields
+
+        let live_fields: Vec<String> = live.field_names().map(String::from).collect();
+        let desired_fields: Vec<(&str, &[String])> = spec.fields
+            .iter()
+            .map(|x| (x.name.as_str(), x.renamed_from.as_slice()))
+            .collect();
+
+        for step in diff_names(&live_fields, &desired_fields, true) {
+            plan.steps.push(match step {
+                NameChange::Rename { from, to } => ModelChange::RenameField { from, to },
+                NameChange::Remove { name } => ModelChange::RemoveField { name },
+                NameChange::Add { name, index } => ModelChange::AddField { name, index },
+                NameChange::Reposition { name, index } => ModelChange::RepositionField { name, index },
+            });
+        }
+
+        // Templates
+
+        let live_templates: Vec<String> = live.template_names().map(String::from).collect();
+        let desired_templates: Vec<(&str, &[String])> = spec.templates
+            .iter()
+            .map(|x| (x.name.as_str(), x.renamed_from.as_slice()))
+            .collect();
+        let spec_templates: HashMap<&str, &CardTemplate> = spec.templates
+            .iter()
+            .map(|x| (x.name.as_str(), &x.template))
+            .collect();
+
+        // Live content, keyed by the name each template will have once renamed
+        let mut live_content: HashMap<String, CardTemplate> = live.templates
+            .iter()
+            .map(|x| (x.name.clone(), CardTemplate::new(x.front.as_str(), x.back.as_str())))
+            .collect();
+
+        for step in diff_names(&live_templates, &desired_templates, false) {
+            plan.steps.push(match step {
+                NameChange::Rename { from, to } => {
+                    if let Some(x) = live_content.remove(&from) {
+                        live_content.insert(to.clone(), x);
+                    }
+                    ModelChange::RenameTemplate { from, to }
+                }
+                NameChange::Remove { name } => ModelChange::RemoveTemplate { name },
+                NameChange::Add { name, .. } => {
+                    let template = spec_templates[name.as_str()].clone();
+                    live_content.insert(name.clone(), template.clone());
+                    ModelChange::AddTemplate { name, template }
+                }
+                NameChange::Reposition { name, index } => ModelChange::RepositionTemplate { name, index },
+            });
+        }
+
+        let changed: HashMap<String, CardTemplate> = spec.templates
+            .iter()
+            .filter(|x| live_content.get(&x.name) != Some(&x.template))
+            .map(|x| (x.name.clone(), x.template.clone()))
+            .collect();
+
+        if !changed.is_empty() {
+            plan.steps.push(ModelChange::UpdateTemplates { templates: changed });
+        }
+
+        // Styling
+
+        if live.css != spec.css {
+            plan.steps.push(ModelChange::UpdateStyling { css: spec.css.clone() });
+        }
+
+        Ok(plan)
+    }
+
+    pub fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    pub fn steps(&self) -> &[ModelChange] {
+        &self.steps
+    }
+
+    /// `true` if the model already matches the spec.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// `true` if any step removes a field or template, which deletes data.
+    pub fn has_removals(&self) -> bool {
+        self.steps.iter().any(ModelChange::is_removal)
+    }
+
+    /// Apply each step in order.
+    /// Refuses to apply a plan that removes fields or templates; use `apply_with_removals` after
+    /// confirming that the data loss is intended.
+    /// If a step fails, the steps before it remain applied.
+    pub async fn apply(&self, client: &Client<'_>) -> Result<(), Error> {
+        if let Some(step) = self.steps.iter().find(|x| x.is_removal()) {
+            return Err(Error::Invalid(format!(
+                "plan for model {:?} would {step}; use apply_with_removals to confirm",
+                self.model_name,
+            )));
+        }
+        self.apply_with_removals(client).await
+    }
+
+    /// Apply each step in order, including steps that remove fields or templates.
+    /// If a step fails, the steps before it remain applied.
+    pub async fn apply_with_removals(&self, client: &Client<'_>) -> Result<(), Error> {
+        for step in &self.steps {
+            step.apply(client, &self.model_name).await?;
+        }
+        Ok(())
+    }
+
+}
+
+impl std::fmt::Display for ModelPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.steps.is_empty() {
+            return write!(f, "model {:?} is up to date", self.model_name);
+        }
+        write!(f, "plan for model {:?}:", self.model_name)?;
+        for (i, step) in self.steps.iter().enumerate() {
+            write!(f, "\n  {}. {step}", i + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Anki compares field and template names case-insensitively.
+fn check_unique_names<'a>(
+    model: &str,
+    kind: &str,
+    names: impl Iterator<Item = &'a str>,
+) -> Result<(), Error> {
+    match find_duplicate(names) {
+        Some(name) => Err(Error::Invalid(format!("model {model:?} has more than one {kind} named {name:?}"))),
+        None => Ok(()),
+    }
+}
+
+/// Anki compares field and template names case-insensitively.
+fn same_name(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum NameChange {
+    Rename { from: String, to: String },
+    Remove { name: String },
+    Add { name: String, index: usize },
+    Reposition { name: String, index: usize },
+}
+
+/// Computes renames, removals, additions and repositions that turn the `live` list of names into
+/// the `desired` list, where each desired name comes with the names it was previously known by.
+/// Names are matched case-insensitively, and a name that only differs in case is renamed.
+/// Only names outside the longest run already in the right relative order are repositioned.
+/// If `add_at_index` is `false`, additions go to the end and are followed by a reposition.
+fn diff_names(live: &[String], desired: &[(&str, &[String])], add_at_index: bool) -> Vec<NameChange> {
+
+    let mut steps = Vec::new();
+    let mut live: Vec<String> = live.to_vec();
+    let desired_names: HashSet<&str> = desired.iter().map(|(x, _)| *x).collect();
+
+    let is_desired = |name: &str| desired.iter().any(|(x, _)| same_name(x, name));
+
+    for (name, renamed_from) in desired {
+        if live.iter().any(|x| x == name) {
+            continue;
+        }
+        let old = live.iter().position(|x| same_name(x, name)).or_else(|| {
+            renamed_from
+                .iter()
+                .filter(|x| !is_desired(x))
+                .find_map(|old| live.iter().position(|x| same_name(x, old)))
+        });
+        if let Some(i) = old {
+            steps.push(NameChange::Rename { from: live[i].clone(), to: name.to_string() });
+            live[i] = name.to_string();
+        }
+    }
+
+    for name in live.iter().filter(|x| !desired_names.contains(x.as_str())) {
+        steps.push(NameChange::Remove { name: name.clone() });
+    }
+    live.retain(|x| desired_names.contains(x.as_str()));
+
+    let keep = longest_ordered_run(&live, desired);
+
+    for (i, (name, _)) in desired.iter().enumerate() {
+        let current = live.iter().position(|x| x == name);
+        if current.is_some() && keep.contains(name) {
+            continue;
+        }
+        if let Some(x) = current {
+            live.remove(x);
+        }
+        // The previous desired name is always in place by now
+        let index = match i {
+            0 => 0,
+            _ => live.iter().position(|x| x == desired[i - 1].0).unwrap() + 1,
+        };
+        match current {
+            Some(x) if x == index => {}
+            Some(_) => steps.push(NameChange::Reposition { name: name.to_string(), index }),
+            None if add_at_index => steps.push(NameChange::Add { name: name.to_string(), index }),
+            None => {
+                steps.push(NameChange::Add { name: name.to_string(), index: live.len() });
+                if index != live.len() {
+                    steps.push(NameChange::Reposition { name: name.to_string(), index });
+                }
+            }
+        }
+        live.insert(index, name.to_string());
+    }
+
+    debug_assert!(live.iter().map(String::as_str).eq(desired.iter().map(|(x, _)| *x)));
+
+    steps
+}
+
+/// Names in `live` that form the longest subsequence already in `desired` order.
+fn longest_ordered_run<'a>(live: &[String], desired: &[(&'a str, &[String])]) -> HashSet<&'a str> {
+
+    let present: Vec<(&str, usize)> = desired
+        .iter()
+        .filter_map(|(name, _)| live.iter().position(|x| x == name).map(|i| (*name, i)))
+        .collect();
+
+    // lengths[i]: length of the longest increasing run ending at i, previous[i]: its predecessor
+    let mut lengths = vec![1; present.len()];
+    let mut previous: Vec<Option<usize>> = vec![None; present.len()];
+
+    for i in 0..present.len() {
+        for j in 0..i {
+            if present[j].1 < present[i].1 && lengths[j] + 1 > lengths[i] {
+                lengths[i] = lengths[j] + 1;
+                previous[i] = Some(j);
+            }
+        }
+    }
+
+    let mut run = HashSet::new();
+    let mut current = (0..present.len()).max_by_key(|&i| lengths[i]);
+    while let Some(i) = current {
+        run.insert(present[i].0);
+        current = previous[i];
+    }
+    run
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn live_model(fields: &[&str], templates: &[(&str, &str, &str)], css: &str) -> ModelInfo {
+        let value = serde_json::json!({
+            "id": 1,
+            "name": "Vocab",
+            "type": 0,
+            "sortf": 0,
+            "css": css,
+            "flds": fields.iter().enumerate().map(|(i, x)| serde_json::json!({
+                "name": x,
+                "ord": i,
+                "font": "Arial",
+                "size": 20,
+            })).collect::<Vec<_>>(),
+            "tmpls": templates.iter().enumerate().map(|(i, (name, front, back))| serde_json::json!({
+                "name": name,
+                "ord": i,
+                "qfmt": front,
+                "afmt": back,
+            })).collect::<Vec<_>>(),
+        });
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn names(xs: &[&str]) -> Vec<String> {
+        xs.iter().map(|x| x.to_string()).collect()
+    }
+
+    fn apply_names(live: &[&str], steps: &[NameChange]) -> Vec<String> {
+        let mut live = names(live);
+        for step in steps {
+            match step {
+                NameChange::Rename { from, to } => {
+                    let i = live.iter().position(|x| x == from).unwrap();
+                    live[i] = to.clone();
+                }
+                NameChange::Remove { name } => live.retain(|x| x != name),
+                NameChange::Add { name, index } => live.insert(*index, name.clone()),
+                NameChange::Reposition { name, index } => {
+                    live.retain(|x| x != name);
+                    live.insert(*index, name.clone());
+                }
+            }
+        }
+        live
+    }
+
+    #[test]
+    fn single_reposition_for_rotation() {
+        let desired: Vec<(&str, &[String])> = vec![("B", &[]), ("C", &[]), ("D", &[]), ("A", &[])];
+        let steps = diff_names(&names(&["A", "B", "C", "D"]), &desired, true);
+        assert_eq!(steps, vec![NameChange::Reposition { name: "A".to_string(), index: 3 }]);
+    }
+
+    #[test]
+    fn reorder_reaches_desired_order() {
+        let live = ["C", "D", "A", "B", "E"];
+        let desired: Vec<(&str, &[String])> = vec![("A", &[]), ("B", &[]), ("X", &[]), ("C", &[]), ("D", &[])];
+        for add_at_index in [true, false] {
+            let steps = diff_names(&names(&live), &desired, add_at_index);
+            assert_eq!(apply_names(&live, &steps), names(&["A", "B", "X", "C", "D"]));
+        }
+    }
+
+    #[test]
+    fn rename_instead_of_remove() {
+        let old = names(&["Definition"]);
+        let desired: Vec<(&str, &[String])> = vec![("Word", &[]), ("Meaning", &old)];
+        let steps = diff_names(&names(&["Word", "Definition"]), &desired, true);
+        assert_eq!(steps, vec![NameChange::Rename {
+            from: "Definition".to_string(),
+            to: "Meaning".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn rename_case_only_difference() {
+        let old = names(&["definition"]);
+        let desired: Vec<(&str, &[String])> = vec![("Word", &[]), ("Meaning", &old)];
+        let steps = diff_names(&names(&["word", "Definition"]), &desired, true);
+        assert_eq!(steps, vec![
+            NameChange::Rename { from: "word".to_string(), to: "Word".to_string() },
+            NameChange::Rename { from: "Definition".to_string(), to: "Meaning".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn up_to_date() {
+        let live = live_model(&["Front", "Back"], &[("Card 1", "{{Front}}", "{{Back}}")], "css");
+        let spec = ModelSpec::new("Vocab")
+            .field("Front")
+            .field("Back")
+            .template("Card 1", CardTemplate::new("{{Front}}", "{{Back}}"))
+            .css("css");
+        let plan = ModelPlan::diff(&spec, Some(&live)).unwrap();
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn missing_model_is_created() {
+        let spec = ModelSpec::new("Vocab").field("Front");
+        let plan = ModelPlan::diff(&spec, None).unwrap();
+        assert_eq!(plan.steps(), &[ModelChange::CreateModel(spec)]);
+    }
+
+    #[test]
+    fn full_plan_order() {
+        let live = live_model(
+            &["Front", "Back", "Notes"],
+            &[("Card 1", "{{Front}}", "{{Back}}"), ("Old", "{{Back}}", "{{Front}}")],
+            "old css",
+        );
+        let spec = ModelSpec::new("Vocab")
+            .field("Front")
+            .field("Extra")
+            .field("Back")
+            .template("Card 1", CardTemplate::new("{{Front}}", "{{Back}}<br>{{Extra}}"))
+            .renamed_template("Reverse", CardTemplate::new("{{Back}}", "{{Front}}"), ["Old"])
+            .template("Typing", CardTemplate::new("{{type:Back}}", "{{Front}}"))
+            .css("new css");
+
+        let plan = ModelPlan::diff(&spec, Some(&live)).unwrap();
+        assert!(plan.has_removals());
+        assert_eq!(plan.steps(), &[
+            ModelChange::RemoveField { name: "Notes".to_string() },
+            ModelChange::AddField { name: "Extra".to_string(), index: 1 },
+            ModelChange::RenameTemplate { from: "Old".to_string(), to: "Reverse".to_string() },
+            ModelChange::AddTemplate {
+                name: "Typing".to_string(),
+                template: CardTemplate::new("{{type:Back}}", "{{Front}}"),
+            },
+            ModelChange::UpdateTemplates {
+                templates: HashMap::from([(
+                    "Card 1".to_string(),
+                    CardTemplate::new("{{Front}}", "{{Back}}<br>{{Extra}}"),
+                )]),
+            },
+            ModelChange::UpdateStyling { css: "new css".to_string() },
+        ]);
+
+        let text = plan.to_string();
+        assert!(text.starts_with("plan for model \"Vocab\":\n  1. remove field \"Notes\""));
+    }
+
+    #[test]
+    fn field_case_change_keeps_content() {
+        let live = live_model(&["front", "Back"], &[("card 1", "{{front}}", "{{Back}}")], "");
+        let spec = ModelSpec::new("Vocab")
+            .field("Front")
+            .field("Back")
+            .template("Card 1", CardTemplate::new("{{front}}", "{{Back}}"));
+        let plan = ModelPlan::diff(&spec, Some(&live)).unwrap();
+        assert!(!plan.has_removals());
+        assert_eq!(plan.steps(), &[
+            ModelChange::RenameField { from: "front".to_string(), to: "Front".to_string() },
+            ModelChange::RenameTemplate { from: "card 1".to_string(), to: "Card 1".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn cloze_mismatch() {
+        let live = live_model(&["Text"], &[("Cloze", "{{cloze:Text}}", "{{cloze:Text}}")], "");
+        let spec = ModelSpec::new("Vocab").field("Text").cloze(true);
+        assert!(ModelPlan::diff(&spec, Some(&live)).is_err());
+    }
+
+    #[test]
+    fn duplicate_field_name() {
+        let live = live_model(&["Front", "Back"], &[("Card 1", "{{Front}}", "{{Back}}")], "");
+        let spec = ModelSpec::new("Vocab")
+            .field("Front")
+            .field("Back")
+            .field("front")
+            .template("Card 1", CardTemplate::new("{{Front}}", "{{Back}}"));
+        for live in [Some(&live), None] {
+            match ModelPlan::diff(&spec, live) {
+                Err(Error::Invalid(x)) => assert!(x.contains("\"front\"")),
+                x => panic!("expected Error::Invalid, got {x:?}"),
+            }
+        }
+    }
+
+}
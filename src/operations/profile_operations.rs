@@ -0,0 +1,74 @@
+use crate::Client;
+use crate::endpoints::{GetActiveProfile, GetProfiles, LoadProfile};
+use crate::error::Error;
+
+// Profile operations
+
+/// Loads each profile in turn and runs `f` with its name, then loads the profile that was active
+/// beforehand, even if `f` fails.
+/// Stops at the first error. Returns the result of `f` for each profile.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), ankiconnect::Error> {
+/// use ankiconnect::Client;
+/// use ankiconnect::endpoints::GetNumCardsReviewedToday;
+/// use ankiconnect::operations::for_each_profile;
+///
+/// let client = Client::default();
+///
+/// let reviewed = for_each_profile(&client, async |_| {
+///     client.invoke_default::<GetNumCardsReviewedToday>().await
+/// }).await?;
+///
+/// for (profile, count) in reviewed {
+///     println!("{profile}: {count}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn for_each_profile<T>(
+    client: &Client<'_>,
+    mut f: impl AsyncFnMut(&str) -> Result<T, Error>,
+) -> Result<Vec<(String, T)>, Error> {
+
+    let original = client.invoke_default::<GetActiveProfile>().await?;
+    let profiles = client.invoke_default::<GetProfiles>().await?;
+
+    let mut results = Vec::new();
+    let mut failure = None;
+
+    for profile in profiles {
+        match run_in_profile(client, &profile, &mut f).await {
+            Ok(x) => results.push((profile, x)),
+            Err(e) => {
+                failure = Some(e);
+                break;
+            }
+        }
+    }
+
+    let restored = load_profile(client, &original).await;
+
+    match (failure, restored) {
+        (Some(e), _) => Err(e),
+        (None, Err(e)) => Err(e),
+        (None, Ok(())) => Ok(results),
+    }
+}
+
+async fn run_in_profile<T>(
+    client: &Client<'_>,
+    profile: &str,
+    f: &mut impl AsyncFnMut(&str) -> Result<T, Error>,
+) -> Result<T, Error> {
+    load_profile(client, profile).await?;
+    f(profile).await
+}
+
+async fn load_profile(client: &Client<'_>, profile: &str) -> Result<(), Error> {
+    if client.invoke(&LoadProfile::new(profile)).await? {
+        Ok(())
+    } else {
+        Err(Error::Operation(format!("could not load profile {profile:?}")))
+    }
+}
@@ -0,0 +1,389 @@
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, SystemTime};
+
+use crate::Client;
+use crate::error::Error;
+use crate::models::{CardReview, DailyCounts, Ease, Interval, Rating, ReviewDay, ReviewKind, ReviewTimestamp};
+use crate::operations::{ReviewCardContext, fetch_reviewed_by_day};
+
+// Review analytics operations
+
+/// How review times map to days.
+/// Anki starts a new day at the rollover hour in local time, 4 AM by default, so a review at
+/// 2 AM counts towards the previous day. Anki-Connect does not expose either setting, so both
+/// have to be given.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DayRollover {
+    /// Hours after local midnight
+    pub hour: u8,
+    /// The local time zone's offset from UTC, in minutes
+    pub utc_offset_minutes: i32,
+}
+
+impl Default for DayRollover {
+    fn default() -> Self {
+        Self {
+            hour: 4,
+            utc_offset_minutes: 0,
+        }
+    }
+}
+
+impl DayRollover {
+
+    pub fn new(hour: u8, utc_offset_minutes: i32) -> Self {
+        Self {
+            hour,
+            utc_offset_minutes,
+        }
+    }
+
+    pub fn day_of(&self, timestamp: ReviewTimestamp) -> ReviewDay {
+        let local = timestamp.as_secs() + i64::from(self.utc_offset_minutes) * 60;
+        ReviewDay((local - i64::from(self.hour) * 3600).div_euclid(86_400))
+    }
+
+    pub fn today(&self) -> ReviewDay {
+        self.day_of(ReviewTimestamp::from_system_time(SystemTime::now()))
+    }
+
+}
+
+/// Passed and failed reviews of cards that had graduated.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Retention {
+    pub passed: u64,
+    pub failed: u64,
+}
+
+impl Retention {
+
+    pub fn total(&self) -> u64 {
+        self.passed + self.failed
+    }
+
+    /// The share of reviews passed, or `None` if there were none.
+    pub fn rate(&self) -> Option<f64> {
+        match self.total() {
+            0 => None,
+            x => Some(self.passed as f64 / x as f64),
+        }
+    }
+
+    fn add(&mut self, review: &CardReview) {
+        if review.ease == Ease::Again.as_u8() {
+            self.failed += 1;
+        } else {
+            self.passed += 1;
+        }
+    }
+
+}
+
+/// A range of intervals, in days. `max_days` is exclusive; `None` means unbounded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IntervalBucket {
+    pub min_days: u32,
+    pub max_days: Option<u32>,
+}
+
+/// Bounds for [`retention_by_interval`]: under a week, under three weeks, under three months,
+/// under a year, and longer.
+pub const DEFAULT_INTERVAL_BOUNDS: &[u32] = &[1, 7, 21, 90, 365];
+
+/// Reviews and time spent on a single day.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DailyTotal {
+    /// Answered reviews
+    pub reviews: u64,
+    /// Manual and rescheduled entries, which Anki logs without an answer
+    pub manual: u64,
+    pub time: Duration,
+}
+
+/// Consecutive days with at least one answered review.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Streaks {
+    /// Ends today, or yesterday if nothing has been reviewed yet today. 0 otherwise
+    pub current: u32,
+    pub longest: u32,
+}
+
+/// A day of a calendar heatmap.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct HeatmapDay {
+    pub day: ReviewDay,
+    pub reviews: u64,
+    /// 0 for no reviews, otherwise 1 to 4 relative to the busiest day in the series
+    pub level: u8,
+}
+
+/// Time spent answering with a given button.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnswerTime {
+    pub count: u64,
+    pub total: Duration,
+}
+
+impl AnswerTime {
+    pub fn average(&self) -> Option<Duration> {
+        u32::try_from(self.count)
+            .ok()
+            .filter(|x| *x > 0)
+            .map(|x| self.total / x)
+    }
+}
+
+/// A day on which the computed total differs from `getNumCardsReviewedByDay`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DayMismatch {
+    pub day: ReviewDay,
+    /// Review log entries counted locally, including manual ones
+    pub computed: u64,
+    pub reported: u64,
+}
+
+/// True retention, i.e. the share of reviews of graduated cards that were not answered "Again",
+/// per deck. `contexts` can be fetched with [`crate::operations::fetch_review_contexts`]; reviews
+/// of cards without a context are left out.
+pub fn retention_by_deck(reviews: &[CardReview], contexts: &HashMap<i64, ReviewCardContext>) -> BTreeMap<String, Retention> {
+    let mut result: BTreeMap<String, Retention> = BTreeMap::new();
+    for review in reviews.iter().filter(|x| is_graduated_review(x)) {
+        if let Some(context) = contexts.get(&review.card_id) {
+            result.entry(context.deck_name.clone()).or_default().add(review);
+        }
+    }
+    result
+}
+
+/// True retention per bucket of the interval the review was testing, i.e. the interval before
+/// the review. `bounds` are the ascending lower bounds of each bucket, in days, e.g.
+/// [`DEFAULT_INTERVAL_BOUNDS`]. Reviews below the first bound are left out.
+pub fn retention_by_interval(reviews: &[CardReview], bounds: &[u32]) -> Vec<(IntervalBucket, Retention)> {
+    let mut result: Vec<(IntervalBucket, Retention)> = bounds
+        .iter()
+        .enumerate()
+        .map(|(index, min_days)| (IntervalBucket {
+            min_days: *min_days,
+            max_days: bounds.get(index + 1).copied(),
+        }, Retention::default()))
+        .collect();
+
+    for review in reviews.iter().filter(|x| is_graduated_review(x)) {
+        let Interval::Days(days) = Interval::from_raw(review.last_interval) else {
+            continue;
+        };
+        let bucket = result
+            .iter_mut()
+            .rev()
+            .find(|(bucket, _)| bucket.min_days <= days);
+        if let Some((_, retention)) = bucket {
+            retention.add(review);
+        }
+    }
+    result
+}
+
+/// Reviews and time spent per day.
+pub fn daily_totals(reviews: &[CardReview], rollover: DayRollover) -> BTreeMap<ReviewDay, DailyTotal> {
+    let mut result: BTreeMap<ReviewDay, DailyTotal> = BTreeMap::new();
+    for review in reviews {
+        let total = result.entry(rollover.day_of(review.timestamp())).or_default();
+        if review.ease == Rating::Manual.as_u8() {
+            total.manual += 1;
+        } else {
+            total.reviews += 1;
+        }
+        total.time += Duration::from_millis(review.review_duration.into());
+    }
+    result
+}
+
+/// The current and longest streaks, given [`daily_totals`].
+pub fn streaks(daily: &BTreeMap<ReviewDay, DailyTotal>, today: ReviewDay) -> Streaks {
+    let mut result = Streaks::default();
+    let mut run = 0;
+    let mut previous: Option<ReviewDay> = None;
+
+    for day in daily.iter().filter(|(_, x)| x.reviews > 0).map(|(day, _)| *day) {
+        run = match previous {
+            Some(x) if x.next() == day => run + 1,
+            _ => 1,
+        };
+        result.longest = result.longest.max(run);
+        previous = Some(day);
+    }
+
+    if previous.is_some_and(|x| x == today || x == today.previous()) {
+        result.current = run;
+    }
+    result
+}
+
+/// A series for a calendar heatmap from `from` to `to` inclusive, with days without reviews
+/// filled in. Empty if `from` is after `to`.
+pub fn heatmap(daily: &BTreeMap<ReviewDay, DailyTotal>, from: ReviewDay, to: ReviewDay) -> Vec<HeatmapDay> {
+    if from > to {
+        return Vec::new();
+    }
+
+    let busiest = daily
+        .range(from..=to)
+        .map(|(_, x)| x.reviews)
+        .max()
+        .unwrap_or(0);
+
+    (from.0..=to.0)
+        .map(ReviewDay)
+        .map(|day| {
+            let reviews = daily.get(&day).map_or(0, |x| x.reviews);
+            let level = match reviews {
+                0 => 0,
+                x => (x * 4).div_ceil(busiest) as u8,
+            };
+            HeatmapDay { day, reviews, level }
+        })
+        .collect()
+}
+
+/// Time spent per answer button. Manual entries are left out.
+pub fn answer_times(reviews: &[CardReview]) -> HashMap<Ease, AnswerTime> {
+    let mut result: HashMap<Ease, AnswerTime> = HashMap::new();
+    for review in reviews {
+        if let Ok(ease) = Ease::try_from(review.ease) {
+            let time = result.entry(ease).or_default();
+            time.count += 1;
+            time.total += Duration::from_millis(review.review_duration.into());
+        }
+    }
+    result
+}
+
+/// Compares [`daily_totals`] with the totals Anki-Connect reports, and returns the days that
+/// differ. Only meaningful if the totals were computed from the whole collection's review log,
+/// with the collection's rollover settings.
+pub async fn cross_check_daily_totals(
+    client: &Client<'_>,
+    daily: &BTreeMap<ReviewDay, DailyTotal>,
+) -> Result<Vec<DayMismatch>, Error> {
+    let reported = fetch_reviewed_by_day(client).await?;
+    Ok(compare_daily_totals(daily, &reported))
+}
+
+fn compare_daily_totals(daily: &BTreeMap<ReviewDay, DailyTotal>, reported: &DailyCounts) -> Vec<DayMismatch> {
+    let mut days: Vec<ReviewDay> = daily.keys().chain(reported.0.keys()).copied().collect();
+    days.sort_unstable();
+    days.dedup();
+
+    days.into_iter()
+        .map(|day| DayMismatch {
+            day,
+            computed: daily.get(&day).map_or(0, |x| x.reviews + x.manual),
+            reported: reported.get(day),
+        })
+        .filter(|x| x.computed != x.reported)
+        .collect()
+}
+
+fn is_graduated_review(review: &CardReview) -> bool {
+    review.review_type == ReviewKind::Review.as_u8() && review.ease != Rating::Manual.as_u8()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400_000;
+    const HOUR: i64 = 3_600_000;
+
+    fn day(text: &str) -> ReviewDay {
+        text.parse().unwrap()
+    }
+
+    fn review(id: i64, card_id: i64, ease: u8, last_interval: i32, review_type: u8) -> CardReview {
+        CardReview::from_tuple(&(id, card_id, -1, ease, 1, last_interval, 2500, 1000 * u32::from(ease), review_type))
+    }
+
+    #[test]
+    fn rollover() {
+        // 2023-11-15T02:00:00Z
+        let timestamp = ReviewTimestamp(1_700_013_600_000);
+        assert_eq!(DayRollover::default().day_of(timestamp), day("2023-11-14"));
+        assert_eq!(DayRollover::new(0, 0).day_of(timestamp), day("2023-11-15"));
+        assert_eq!(DayRollover::new(4, 180).day_of(timestamp), day("2023-11-15"));
+        assert_eq!(DayRollover::new(4, -300).day_of(timestamp), day("2023-11-14"));
+    }
+
+    #[test]
+    fn retention() {
+        let reviews = [
+            review(1, 1, 3, 3, 1),
+            review(2, 1, 1, 30, 1),
+            review(3, 2, 3, 30, 1),
+            review(4, 2, 1, -600, 0),
+            review(5, 3, 4, 400, 1),
+            review(6, 3, 0, 400, 4),
+        ];
+        let contexts = HashMap::from([
+            (1, ReviewCardContext { note_id: 1, deck_name: "A".to_string() }),
+            (2, ReviewCardContext { note_id: 2, deck_name: "B".to_string() }),
+        ]);
+
+        let by_deck = retention_by_deck(&reviews, &contexts);
+        assert_eq!(by_deck["A"], Retention { passed: 1, failed: 1 });
+        assert_eq!(by_deck["B"].rate(), Some(1.0));
+        assert_eq!(by_deck.len(), 2);
+
+        let by_interval = retention_by_interval(&reviews, &[1, 21]);
+        assert_eq!(by_interval, vec![
+            (IntervalBucket { min_days: 1, max_days: Some(21) }, Retention { passed: 1, failed: 0 }),
+            (IntervalBucket { min_days: 21, max_days: None }, Retention { passed: 2, failed: 1 }),
+        ]);
+    }
+
+    #[test]
+    fn totals_streaks_and_heatmap() {
+        let start = 1_700_000_000_000;
+        let reviews = [
+            review(start, 1, 3, 1, 1),
+            review(start + HOUR, 1, 1, 1, 1),
+            review(start + DAY, 1, 3, 1, 1),
+            review(start + 3 * DAY, 1, 0, 1, 4),
+            review(start + 4 * DAY, 1, 4, 1, 1),
+            review(start + 5 * DAY, 1, 2, 1, 1),
+        ];
+        let daily = daily_totals(&reviews, DayRollover::new(0, 0));
+        let first = day("2023-11-14");
+        assert_eq!(daily[&first], DailyTotal { reviews: 2, manual: 0, time: Duration::from_secs(4) });
+        assert_eq!(daily[&ReviewDay(first.0 + 3)].manual, 1);
+
+        assert_eq!(streaks(&daily, ReviewDay(first.0 + 5)), Streaks { current: 2, longest: 2 });
+        assert_eq!(streaks(&daily, ReviewDay(first.0 + 6)), Streaks { current: 2, longest: 2 });
+        assert_eq!(streaks(&daily, ReviewDay(first.0 + 7)), Streaks { current: 0, longest: 2 });
+
+        let levels: Vec<(u64, u8)> = heatmap(&daily, first.previous(), ReviewDay(first.0 + 2))
+            .iter()
+            .map(|x| (x.reviews, x.level))
+            .collect();
+        assert_eq!(levels, vec![(0, 0), (2, 4), (1, 2), (0, 0)]);
+        assert!(heatmap(&daily, ReviewDay(first.0 + 2), first).is_empty());
+
+        let times = answer_times(&reviews);
+        assert_eq!(times[&Ease::Good].average(), Some(Duration::from_secs(3)));
+        assert_eq!(times[&Ease::Again].count, 1);
+        assert_eq!(times.len(), 4);
+
+        let reported = DailyCounts::from_reported(&[
+            ("2023-11-14".to_string(), 2),
+            ("2023-11-15".to_string(), 2),
+            ("2023-11-17".to_string(), 1),
+        ]).unwrap();
+        let mismatches = compare_daily_totals(&daily, &reported);
+        assert_eq!(mismatches, vec![
+            DayMismatch { day: day("2023-11-15"), computed: 1, reported: 2 },
+            DayMismatch { day: day("2023-11-18"), computed: 1, reported: 0 },
+            DayMismatch { day: day("2023-11-19"), computed: 1, reported: 0 },
+        ]);
+    }
+
+}
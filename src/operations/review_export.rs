@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use serde::ser::{SerializeMap, Serializer};
+use serde_json::Value;
+use strum::{AsRefStr, EnumString};
+
+use crate::Client;
+use crate::endpoints::CardsInfo;
+use crate::error::Error;
+use crate::models::{CardReview, Interval};
+
+// Review export operations
+
+/// The formats [`ReviewExporter`] can write.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReviewExportFormat {
+    /// Comma-separated values with a header row, quoted as described in RFC 4180
+    Csv,
+    /// One JSON object per line, keyed by column name, with keys in column order
+    JsonLines,
+}
+
+/// A column of a review export. Column names are the snake case variant names.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumString, AsRefStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum ReviewColumn {
+    /// The review's ID, in milliseconds from UNIX epoch
+    ReviewId,
+    /// The review time as an RFC 3339 timestamp in UTC
+    Timestamp,
+    CardId,
+    /// Requires card context, empty otherwise
+    NoteId,
+    /// Requires card context, empty otherwise
+    DeckName,
+    Usn,
+    /// e.g. `good`, or the raw ease if Anki's value is unknown
+    Rating,
+    /// e.g. `review`, or the raw type if Anki's value is unknown
+    Kind,
+    #[strum(serialize = "new_interval_secs")]
+    NewInterval,
+    #[strum(serialize = "last_interval_secs")]
+    LastInterval,
+    /// In permille
+    Factor,
+    #[strum(serialize = "duration_ms")]
+    Duration,
+}
+
+impl ReviewColumn {
+
+    pub const DEFAULT: &'static [ReviewColumn] = &[
+        ReviewColumn::ReviewId,
+        ReviewColumn::Timestamp,
+        ReviewColumn::CardId,
+        ReviewColumn::Rating,
+        ReviewColumn::Kind,
+        ReviewColumn::NewInterval,
+        ReviewColumn::LastInterval,
+        ReviewColumn::Factor,
+        ReviewColumn::Duration,
+    ];
+
+    fn value(&self, review: &CardReview, context: Option<&ReviewCardContext>) -> Value {
+        match self {
+            ReviewColumn::ReviewId => review.id.into(),
+            ReviewColumn::Timestamp => review.timestamp().to_string().into(),
+            ReviewColumn::CardId => review.card_id.into(),
+            ReviewColumn::NoteId => context.map_or(Value::Null, |x| x.note_id.into()),
+            ReviewColumn::DeckName => context.map_or(Value::Null, |x| x.deck_name.clone().into()),
+            ReviewColumn::Usn => review.usn.into(),
+            ReviewColumn::Rating => match review.rating() {
+                Ok(x) => x.as_ref().into(),
+                Err(x) => x.into(),
+            },
+            ReviewColumn::Kind => match review.kind() {
+                Ok(x) => x.as_ref().into(),
+                Err(x) => x.into(),
+            },
+            ReviewColumn::NewInterval => Interval::from_raw(review.new_interval).as_seconds().into(),
+            ReviewColumn::LastInterval => Interval::from_raw(review.last_interval).as_seconds().into(),
+            ReviewColumn::Factor => review.new_factor.into(),
+            ReviewColumn::Duration => review.review_duration.into(),
+        }
+    }
+
+}
+
+/// Information about a reviewed card that is not part of its review log.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewCardContext {
+    pub note_id: u64,
+    pub deck_name: String,
+}
+
+/// Writes reviews to CSV or JSON Lines, one row at a time.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), ankiconnect::Error> {
+/// use ankiconnect::Client;
+/// use ankiconnect::endpoints::CardReviews;
+/// use ankiconnect::models::CardReview;
+/// use ankiconnect::operations::{fetch_review_contexts, ReviewColumn, ReviewExporter, ReviewExportFormat};
+///
+/// let client = Client::default();
+/// let reviews: Vec<CardReview> = client.invoke(&CardReviews::new("Default", 0)).await?
+///     .iter()
+///     .map(CardReview::from_tuple)
+///     .collect();
+///
+/// let card_ids: Vec<i64> = reviews.iter().map(|x| x.card_id).collect();
+/// let contexts = fetch_review_contexts(&client, &card_ids).await?;
+///
+/// let file = std::fs::File::create("reviews.csv").map_err(ankiconnect::Error::Io)?;
+/// let mut exporter = ReviewExporter::new(file, ReviewExportFormat::Csv)
+///     .with_columns([ReviewColumn::Timestamp, ReviewColumn::DeckName, ReviewColumn::Rating]);
+/// exporter.write_all(&reviews, &contexts)?;
+/// exporter.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReviewExporter<W: Write> {
+    writer: W,
+    format: ReviewExportFormat,
+    columns: Vec<ReviewColumn>,
+    header_written: bool,
+}
+
+impl<W: Write> ReviewExporter<W> {
+
+    /// Uses [`ReviewColumn::DEFAULT`] until columns are set.
+    pub fn new(writer: W, format: ReviewExportFormat) -> Self {
+        Self {
+            writer,
+            format,
+            columns: ReviewColumn::DEFAULT.to_vec(),
+            header_written: false,
+        }
+    }
+
+    pub fn with_columns(mut self, columns: impl Into<Vec<ReviewColumn>>) -> Self {
+        self.columns = columns.into();
+        self
+    }
+
+    pub fn write(&mut self, review: &CardReview, context: Option<&ReviewCardContext>) -> Result<(), Error> {
+        self.write_header()?;
+        let values = self.columns.iter().map(|x| x.value(review, context));
+        match self.format {
+            ReviewExportFormat::Csv => {
+                let cells: Vec<String> = values.map(|x| csv_cell(&x)).collect();
+                writeln!(self.writer, "{}", cells.join(",")).map_err(Error::Io)
+            },
+            ReviewExportFormat::JsonLines => {
+                // A `serde_json::Map` would sort the keys
+                let mut serializer = serde_json::Serializer::new(&mut self.writer);
+                let mut row = serializer
+                    .serialize_map(Some(self.columns.len()))
+                    .map_err(Error::SerializeSerde)?;
+                for (column, value) in self.columns.iter().zip(values) {
+                    row.serialize_entry(column.as_ref(), &value).map_err(Error::SerializeSerde)?;
+                }
+                row.end().map_err(Error::SerializeSerde)?;
+                writeln!(self.writer).map_err(Error::Io)
+            },
+        }
+    }
+
+    /// Writes each review with the context of its card, if there is one.
+    pub fn write_all(&mut self, reviews: &[CardReview], contexts: &HashMap<i64, ReviewCardContext>) -> Result<(), Error> {
+        for review in reviews {
+            self.write(review, contexts.get(&review.card_id))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the CSV header if no rows were written, flushes, and returns the writer.
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.write_header()?;
+        self.writer.flush().map_err(Error::Io)?;
+        Ok(self.writer)
+    }
+
+    fn write_header(&mut self) -> Result<(), Error> {
+        if self.header_written || self.format != ReviewExportFormat::Csv {
+            return Ok(());
+        }
+        self.header_written = true;
+        let names: Vec<String> = self.columns
+            .iter()
+            .map(|x| csv_cell(&x.as_ref().into()))
+            .collect();
+        writeln!(self.writer, "{}", names.join(",")).map_err(Error::Io)
+    }
+
+}
+
+/// Looks up the note and deck of each card.
+/// `cardsToNotes` returns distinct note IDs which can't be matched back to cards, so this uses
+/// `cardsInfo` instead. Cards that no longer exist are left out.
+pub async fn fetch_review_contexts(client: &Client<'_>, card_ids: &[i64]) -> Result<HashMap<i64, ReviewCardContext>, Error> {
+    let mut cards: Vec<u64> = card_ids
+        .iter()
+        .filter_map(|x| u64::try_from(*x).ok())
+        .collect();
+    cards.sort_unstable();
+    cards.dedup();
+
+    if cards.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let info = client.invoke(&CardsInfo::new(&cards[..])).await?;
+    Ok(info
+        .into_iter()
+        .filter_map(|x| x.into_option())
+        .map(|x| (x.card_id as i64, ReviewCardContext {
+            note_id: x.note,
+            deck_name: x.deck_name,
+        }))
+        .collect())
+}
+
+fn csv_cell(value: &Value) -> String {
+    let text = match value {
+        Value::Null => return String::new(),
+        Value::String(x) => x.clone(),
+        x => x.to_string(),
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reviews() -> Vec<CardReview> {
+        vec![
+            CardReview::from_tuple(&(1_700_000_000_123, 7, -1, 3, -600, 0, 2500, 6000, 0)),
+            CardReview::from_tuple(&(1_700_086_400_000, 7, 12, 1, 1, -600, 2300, 9000, 1)),
+        ]
+    }
+
+    fn contexts() -> HashMap<i64, ReviewCardContext> {
+        HashMap::from([(7, ReviewCardContext {
+            note_id: 70,
+            deck_name: "Languages, \"Spanish\"".to_string(),
+        })])
+    }
+
+    #[test]
+    fn csv() {
+        let columns = [ReviewColumn::Timestamp, ReviewColumn::DeckName, ReviewColumn::Rating, ReviewColumn::NewInterval];
+        let mut exporter = ReviewExporter::new(Vec::new(), ReviewExportFormat::Csv).with_columns(columns);
+        exporter.write_all(&reviews(), &contexts()).unwrap();
+        exporter.write(&reviews()[0], None).unwrap();
+        let text = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        assert_eq!(text, "\
+timestamp,deck_name,rating,new_interval_secs
+2023-11-14T22:13:20.123Z,\"Languages, \"\"Spanish\"\"\",good,600
+2023-11-15T22:13:20.000Z,\"Languages, \"\"Spanish\"\"\",again,86400
+2023-11-14T22:13:20.123Z,,good,600
+");
+    }
+
+    #[test]
+    fn csv_without_rows() {
+        let exporter = ReviewExporter::new(Vec::new(), ReviewExportFormat::Csv)
+            .with_columns([ReviewColumn::ReviewId, ReviewColumn::Duration]);
+        assert_eq!(exporter.finish().unwrap(), b"review_id,duration_ms\n");
+    }
+
+    #[test]
+    fn json_lines() {
+        let columns = [ReviewColumn::ReviewId, ReviewColumn::NoteId, ReviewColumn::Kind];
+        let mut exporter = ReviewExporter::new(Vec::new(), ReviewExportFormat::JsonLines).with_columns(columns);
+        exporter.write_all(&reviews(), &contexts()).unwrap();
+        let text = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let rows: Vec<Value> = text.lines().map(|x| serde_json::from_str(x).unwrap()).collect();
+        assert_eq!(rows, vec![
+            serde_json::json!({"review_id": 1_700_000_000_123_i64, "note_id": 70, "kind": "learning"}),
+            serde_json::json!({"review_id": 1_700_086_400_000_i64, "note_id": 70, "kind": "review"}),
+        ]);
+    }
+
+    #[test]
+    fn json_lines_keep_column_order() {
+        let columns = [ReviewColumn::Rating, ReviewColumn::ReviewId, ReviewColumn::CardId];
+        let mut exporter = ReviewExporter::new(Vec::new(), ReviewExportFormat::JsonLines).with_columns(columns);
+        exporter.write(&reviews()[0], None).unwrap();
+        let text = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        assert_eq!(text, "{\"rating\":\"good\",\"review_id\":1700000000123,\"card_id\":7}\n");
+    }
+
+}
@@ -0,0 +1,523 @@
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::time::SystemTime;
+
+use crate::Client;
+use crate::endpoints::{CardsInfo, InsertReviews};
+use crate::error::Error;
+use crate::models::{CardReview, Ease, Interval, Rating, ReviewKind, ReviewLogEntry, ReviewTimestamp};
+use crate::operations::{fetch_review_ids_since, fetch_reviews_of_cards};
+
+// Review import operations
+
+/// The header [`ReviewImport::run`] expects on the first line of its input.
+pub const REVIEW_IMPORT_HEADER: &str = "card_key,timestamp,rating,interval,duration";
+
+const DEFAULT_CHUNK_SIZE: usize = 500;
+
+/// Anki's default starting ease, in permille.
+const DEFAULT_FACTOR: u32 = 2500;
+
+/// A row of a review history import that was not inserted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RejectedRow {
+    /// 1-based, counting the header
+    pub line: usize,
+    pub reason: String,
+}
+
+/// The outcome of [`ReviewImport::run`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReviewImportReport {
+
+    /// Reviews that were inserted, or would have been in a dry run
+    pub inserted: Vec<CardReview>,
+
+    /// Rows that could not be parsed
+    pub malformed: Vec<RejectedRow>,
+
+    /// Rows with a value outside of what Anki accepts, e.g. a rating of 5 or a future timestamp
+    pub out_of_range: Vec<RejectedRow>,
+
+    /// Rows whose key the resolver did not map, or mapped to a card that does not exist
+    pub unmapped: Vec<RejectedRow>,
+
+    /// Rows that repeat an earlier row, or a review already in the collection
+    pub duplicates: Vec<RejectedRow>,
+}
+
+impl ReviewImportReport {
+    pub fn rejected(&self) -> usize {
+        self.malformed.len() + self.out_of_range.len() + self.unmapped.len() + self.duplicates.len()
+    }
+}
+
+/// Imports review histories from other spaced-repetition tools.
+///
+/// The input is CSV, with the header [`REVIEW_IMPORT_HEADER`] and one review per line:
+///
+/// | Column      | Content                                                                 |
+/// |-------------|-------------------------------------------------------------------------|
+/// | `card_key`  | The other tool's identifier for the card, passed to the resolver        |
+/// | `timestamp` | When the review happened, in RFC 3339, e.g. `2023-11-14T22:13:20Z`       |
+/// | `rating`    | `1` to `4`, or `again`, `hard`, `good`, `easy`                          |
+/// | `interval`  | The interval set by the review, in whole days. `0` if still learning    |
+/// | `duration`  | Time taken to answer, in milliseconds. May be empty                     |
+///
+/// Fields may be quoted as described in RFC 4180, but may not contain line breaks.
+///
+/// Each card's reviews become learning reviews until one sets an interval, and reviews after
+/// that. As in Anki, the review that resets a card's interval to 0 is still a review, and the
+/// reviews after it are relearning reviews until one sets an interval again. Other tools don't
+/// record Anki's ease factor, so every review from the first one that sets an interval on gets
+/// the same factor, 2500 (250%) unless set with [`ReviewImport::with_factor`].
+///
+/// Anki uses the review time as the review's ID, so a review at the same millisecond as any
+/// other review in the collection is moved to the next free millisecond. Reviews of the same card
+/// at the same time, and reviews whose ID the card already has in its review log, are reported
+/// as duplicates. Reviews of deleted cards are not visible to Anki-Connect, so an ID used by one
+/// of them makes the insert fail.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), ankiconnect::Error> {
+/// use std::collections::HashMap;
+/// use ankiconnect::Client;
+/// use ankiconnect::operations::ReviewImport;
+///
+/// let client = Client::default();
+/// let cards: HashMap<String, u64> = HashMap::from([("mochi-42".to_string(), 1_700_000_000_000)]);
+///
+/// let file = std::fs::File::open("history.csv").map_err(ankiconnect::Error::Io)?;
+/// let report = ReviewImport::new(|key: &str| cards.get(key).copied())
+///     .with_dry_run(true)
+///     .run(&client, std::io::BufReader::new(file))
+///     .await?;
+/// println!("{} to insert, {} rejected", report.inserted.len(), report.rejected());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReviewImport<F: FnMut(&str) -> Option<u64>> {
+    resolver: F,
+    chunk_size: usize,
+    dry_run: bool,
+    factor: u32,
+}
+
+/// A parsed row of the import, before its key is resolved.
+#[derive(Clone, Debug, PartialEq)]
+struct ImportedRow {
+    line: usize,
+    card_key: String,
+    timestamp: ReviewTimestamp,
+    ease: Ease,
+    interval_days: u32,
+    duration: u32,
+}
+
+impl<F: FnMut(&str) -> Option<u64>> ReviewImport<F> {
+
+    /// `resolver` maps the other tool's card keys to Anki card IDs.
+    pub fn new(resolver: F) -> Self {
+        Self {
+            resolver,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            dry_run: false,
+            factor: DEFAULT_FACTOR,
+        }
+    }
+
+    /// The ease factor given to reviews of cards that have an interval, in permille.
+    /// Defaults to 2500, Anki's default starting ease.
+    pub fn with_factor(mut self, factor: u32) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// The number of reviews sent in each `insertReviews` request. Defaults to 500.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Checks everything and reports what would be inserted, without inserting anything.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Reads and checks the whole input before inserting anything.
+    /// If an insert fails, the chunks before it stay inserted and the error is returned.
+    pub async fn run(mut self, client: &Client<'_>, reader: impl BufRead) -> Result<ReviewImportReport, Error> {
+        let now = ReviewTimestamp::from_system_time(SystemTime::now());
+        let mut report = ReviewImportReport::default();
+
+        let rows = parse_rows(reader, now, &mut report)?;
+        let rows = resolve_rows(rows, &mut self.resolver, &mut report);
+
+        // Drop cards the resolver made up
+        let mut cards: Vec<u64> = rows.iter().map(|(card, _)| *card).collect();
+        cards.sort_unstable();
+        cards.dedup();
+        let existing: HashSet<u64> = if cards.is_empty() {
+            HashSet::new()
+        } else {
+            cards
+                .iter()
+                .zip(client.invoke(&CardsInfo::new(&cards[..])).await?)
+                .filter(|(_, x)| x.0.is_some())
+                .map(|(card, _)| *card)
+                .collect()
+        };
+        let rows: Vec<(u64, ImportedRow)> = rows
+            .into_iter()
+            .filter(|(card, row)| {
+                let exists = existing.contains(card);
+                if !exists {
+                    report.unmapped.push(RejectedRow {
+                        line: row.line,
+                        reason: format!("card {card} for key {:?} does not exist", row.card_key),
+                    });
+                }
+                exists
+            })
+            .collect();
+
+        let cards: Vec<u64> = existing.into_iter().collect();
+        let logged: HashSet<(i64, i64)> = if cards.is_empty() {
+            HashSet::new()
+        } else {
+            fetch_reviews_of_cards(client, &cards).await?
+                .iter()
+                .map(|x| (x.card_id, x.id))
+                .collect()
+        };
+
+        // IDs of other cards' reviews, which imported reviews have to avoid
+        let used_ids = match rows.iter().map(|(_, x)| x.timestamp.as_millis()).min() {
+            Some(start) => fetch_review_ids_since(client, start).await?,
+            None => HashSet::new(),
+        };
+
+        report.inserted = build_reviews(rows, &logged, used_ids, self.factor, &mut report);
+
+        if !self.dry_run {
+            for chunk in report.inserted.chunks(self.chunk_size) {
+                let request = InsertReviews::new_from(chunk);
+                request.validate()?;
+                client.invoke(&request).await?;
+            }
+        }
+
+        sort_rejected(&mut report);
+        Ok(report)
+    }
+
+}
+
+fn parse_rows(reader: impl BufRead, now: ReviewTimestamp, report: &mut ReviewImportReport) -> Result<Vec<ImportedRow>, Error> {
+    let mut lines = reader.lines().enumerate();
+
+    let header = match lines.next() {
+        Some((_, line)) => line.map_err(Error::Io)?,
+        None => return Ok(Vec::new()),
+    };
+    let header: Vec<String> = split_csv_line(header.trim_start_matches('\u{feff}'))
+        .map_err(Error::Invalid)?
+        .iter()
+        .map(|x| x.trim().to_ascii_lowercase())
+        .collect();
+    if header.join(",") != REVIEW_IMPORT_HEADER {
+        return Err(Error::Invalid(format!("expected the header {REVIEW_IMPORT_HEADER:?}")));
+    }
+
+    let mut rows = Vec::new();
+    for (index, line) in lines {
+        let line_number = index + 1;
+        let line = line.map_err(Error::Io)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_row(line_number, &line, now) {
+            Ok(row) => rows.push(row),
+            Err(RowError::Malformed(reason)) => report.malformed.push(RejectedRow { line: line_number, reason }),
+            Err(RowError::OutOfRange(reason)) => report.out_of_range.push(RejectedRow { line: line_number, reason }),
+        }
+    }
+    Ok(rows)
+}
+
+enum RowError {
+    Malformed(String),
+    OutOfRange(String),
+}
+
+fn parse_row(line_number: usize, line: &str, now: ReviewTimestamp) -> Result<ImportedRow, RowError> {
+    let fields = split_csv_line(line).map_err(RowError::Malformed)?;
+    let [card_key, timestamp, rating, interval, duration] = &fields[..] else {
+        return Err(RowError::Malformed(format!("expected 5 fields, found {}", fields.len())));
+    };
+
+    let card_key = card_key.trim();
+    if card_key.is_empty() {
+        return Err(RowError::Malformed("card key is empty".to_string()));
+    }
+
+    let timestamp: ReviewTimestamp = timestamp.trim().parse().map_err(|e| RowError::Malformed(format!("{e}")))?;
+    if timestamp.as_millis() <= 0 || timestamp > now {
+        return Err(RowError::OutOfRange(format!("timestamp {timestamp} is not in the past")));
+    }
+
+    let rating = rating.trim();
+    let ease = match rating.parse::<i64>() {
+        Ok(x) => u8::try_from(x)
+            .ok()
+            .and_then(|x| Ease::try_from(x).ok())
+            .ok_or_else(|| RowError::OutOfRange(format!("rating {x} is not between 1 and 4")))?,
+        Err(_) => match rating.parse::<Rating>().ok().and_then(|x| x.as_ease()) {
+            Some(x) => x,
+            None => return Err(RowError::Malformed(format!("invalid rating {rating:?}"))),
+        },
+    };
+
+    let interval = interval.trim();
+    let interval_days = match interval.parse::<i64>() {
+        Ok(x) => u32::try_from(x)
+            .ok()
+            .filter(|x| i32::try_from(*x).is_ok())
+            .ok_or_else(|| RowError::OutOfRange(format!("interval {x} is negative or too large")))?,
+        Err(_) => return Err(RowError::Malformed(format!("invalid interval {interval:?}"))),
+    };
+
+    let duration = duration.trim();
+    let duration = match duration {
+        "" => 0,
+        _ => match duration.parse::<i64>() {
+            Ok(x) => u32::try_from(x)
+                .map_err(|_| RowError::OutOfRange(format!("duration {x} is negative or too large")))?,
+            Err(_) => return Err(RowError::Malformed(format!("invalid duration {duration:?}"))),
+        },
+    };
+
+    Ok(ImportedRow {
+        line: line_number,
+        card_key: card_key.to_string(),
+        timestamp,
+        ease,
+        interval_days,
+        duration,
+    })
+}
+
+fn resolve_rows(
+    rows: Vec<ImportedRow>,
+    resolver: &mut impl FnMut(&str) -> Option<u64>,
+    report: &mut ReviewImportReport,
+) -> Vec<(u64, ImportedRow)> {
+    let mut resolved = HashMap::new();
+    let mut result = Vec::with_capacity(rows.len());
+    for row in rows {
+        let card = *resolved
+            .entry(row.card_key.clone())
+            .or_insert_with(|| resolver(&row.card_key).filter(|x| i64::try_from(*x).is_ok()));
+        match card {
+            Some(card) => result.push((card, row)),
+            None => report.unmapped.push(RejectedRow {
+                line: row.line,
+                reason: format!("no card for key {:?}", row.card_key),
+            }),
+        }
+    }
+    result
+}
+
+/// Turns rows into reviews, in order of time. `logged` holds the card and review IDs already in
+/// the review logs of the imported cards, and `used_ids` the IDs of any other reviews to avoid.
+fn build_reviews(
+    mut rows: Vec<(u64, ImportedRow)>,
+    logged: &HashSet<(i64, i64)>,
+    mut used_ids: HashSet<i64>,
+    factor: u32,
+    report: &mut ReviewImportReport,
+) -> Vec<CardReview> {
+    rows.sort_by_key(|(card, row)| (row.timestamp, *card, row.line));
+
+    let mut seen = HashSet::new();
+    used_ids.extend(logged.iter().map(|(_, id)| *id));
+    let mut last_intervals: HashMap<u64, u32> = HashMap::new();
+    let mut graduated: HashSet<u64> = HashSet::new();
+    let mut reviews = Vec::with_capacity(rows.len());
+
+    for (card, row) in rows {
+        let card_id = card as i64;
+        let timestamp = row.timestamp.as_millis();
+        if !seen.insert((card_id, timestamp)) || logged.contains(&(card_id, timestamp)) {
+            report.duplicates.push(RejectedRow {
+                line: row.line,
+                reason: format!("card {card} already has a review at {}", row.timestamp),
+            });
+            continue;
+        }
+
+        let mut id = timestamp;
+        while !used_ids.insert(id) {
+            id += 1;
+        }
+
+        let last_interval = last_intervals.insert(card, row.interval_days).unwrap_or(0);
+        let kind = if !graduated.contains(&card) {
+            ReviewKind::Learning
+        } else if last_interval == 0 {
+            ReviewKind::Relearning
+        } else {
+            ReviewKind::Review
+        };
+        if row.interval_days > 0 {
+            graduated.insert(card);
+        }
+        let entry = ReviewLogEntry {
+            timestamp: ReviewTimestamp(id),
+            card_id,
+            usn: -1,
+            rating: row.ease.into(),
+            new_interval: Interval::Days(row.interval_days),
+            last_interval: Interval::Days(last_interval),
+            new_factor: if graduated.contains(&card) { factor } else { 0 },
+            review_duration: row.duration,
+            kind,
+        };
+        reviews.push(CardReview::from(&entry));
+    }
+
+    reviews
+}
+
+fn sort_rejected(report: &mut ReviewImportReport) {
+    for rows in [&mut report.malformed, &mut report.out_of_range, &mut report.unmapped, &mut report.duplicates] {
+        rows.sort_by_key(|x| x.line);
+    }
+}
+
+/// Splits a line of CSV into unquoted fields.
+fn split_csv_line(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            },
+            (true, '"') => quoted = false,
+            (false, '"') if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            },
+            (false, ',') => fields.push(std::mem::take(&mut field)),
+            (_, c) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: ReviewTimestamp = ReviewTimestamp(1_800_000_000_000);
+
+    fn parse(text: &str) -> (Vec<ImportedRow>, ReviewImportReport) {
+        let mut report = ReviewImportReport::default();
+        let rows = parse_rows(text.as_bytes(), NOW, &mut report).unwrap();
+        (rows, report)
+    }
+
+    fn lines(rows: &[RejectedRow]) -> Vec<usize> {
+        rows.iter().map(|x| x.line).collect()
+    }
+
+    #[test]
+    fn split_quoted() {
+        assert_eq!(split_csv_line(r#"a,"b, ""c""",,d"#).unwrap(), vec!["a", "b, \"c\"", "", "d"]);
+        assert!(split_csv_line(r#"a,"b"#).is_err());
+    }
+
+    #[test]
+    fn parse_and_reject() {
+        let (rows, report) = parse("\
+card_key,timestamp,rating,interval,duration
+\"deck, 1\",2023-11-14T22:13:20Z,good,0,5000
+a,2023-11-15T22:13:20Z,1,3,
+a,yesterday,1,3,
+a,2023-11-15T22:13:20Z,5,3,
+a,2099-01-01T00:00:00Z,3,3,
+a,2023-11-15T22:13:20Z,3,-1,
+a,2023-11-15T22:13:20Z,3,1
+");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].card_key, "deck, 1");
+        assert_eq!(rows[0].ease, Ease::Good);
+        assert_eq!(rows[1].ease, Ease::Again);
+        assert_eq!(rows[1].duration, 0);
+        assert_eq!(lines(&report.malformed), vec![4, 8]);
+        assert_eq!(lines(&report.out_of_range), vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn wrong_header() {
+        let mut report = ReviewImportReport::default();
+        let result = parse_rows("key,time\n".as_bytes(), NOW, &mut report);
+        assert!(matches!(result, Err(Error::Invalid(_))));
+    }
+
+    #[test]
+    fn resolve_and_build() {
+        let (rows, mut report) = parse("\
+card_key,timestamp,rating,interval,duration
+a,2023-11-14T22:13:20Z,3,0,1000
+a,2023-11-15T22:13:20Z,3,1,1000
+b,2023-11-15T22:13:20Z,4,4,1000
+a,2023-11-15T22:13:20Z,3,1,1000
+c,2023-11-16T22:13:20Z,3,1,1000
+a,2023-11-17T22:13:20Z,1,0,1000
+b,2023-11-20T22:13:20Z,3,9,1000
+a,2023-11-17T22:23:20Z,3,0,1000
+a,2023-11-18T22:13:20Z,3,1,1000
+a,2023-11-19T22:13:20Z,3,3,1000
+");
+        let cards = HashMap::from([("a", 10), ("b", 20)]);
+        let rows = resolve_rows(rows, &mut |key: &str| cards.get(key).copied(), &mut report);
+        assert_eq!(lines(&report.unmapped), vec![6]);
+
+        let logged = HashSet::from([(20, 1_700_518_400_000)]);
+        // A review of another card
+        let used_ids = HashSet::from([1_700_000_000_000]);
+        let reviews = build_reviews(rows, &logged, used_ids, 2300, &mut report);
+        assert_eq!(lines(&report.duplicates), vec![5, 8]);
+
+        let summary: Vec<(i64, i64, u8, i32, i32, u32, u8)> = reviews
+            .iter()
+            .map(|x| (x.id, x.card_id, x.ease, x.new_interval, x.last_interval, x.new_factor, x.review_type))
+            .collect();
+        assert_eq!(summary, vec![
+            // Moved apart from the other card's review
+            (1_700_000_000_001, 10, 3, 0, 0, 0, 0),
+            (1_700_086_400_000, 10, 3, 1, 0, 2300, 0),
+            // Moved apart from card 10's review at the same time
+            (1_700_086_400_001, 20, 4, 4, 0, 2300, 0),
+            // The lapse is a review, and the reviews until the card has an interval again relearning
+            (1_700_259_200_000, 10, 1, 0, 1, 2300, 1),
+            (1_700_259_800_000, 10, 3, 0, 0, 2300, 2),
+            (1_700_345_600_000, 10, 3, 1, 0, 2300, 2),
+            (1_700_432_000_000, 10, 3, 3, 1, 2300, 1),
+        ]);
+        assert!(InsertReviews::new_from(&reviews).validate().is_ok());
+    }
+
+}
@@ -0,0 +1,237 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::Client;
+use crate::endpoints::{CardReviews, DeckNamesAndIds, GetLatestReviewId};
+use crate::error::Error;
+use crate::models::{CardReview, ReviewTimestamp};
+
+// Review log operations
+
+/// Remembers how far the review log of each deck has been read, so that [`ReviewLogCursor::fetch`]
+/// returns the reviews added to each deck since the previous fetch.
+///
+/// Decks are tracked by ID, so renamed decks are followed. Reviews are attributed to the deck a
+/// card is in when they are fetched, so moving cards between decks can cause reviews to be
+/// returned twice or never:
+/// - Reviews of a moved card that are newer than the cursor of its new deck are returned again,
+///   even if they were already returned for its old deck. Deduplicate by review ID if this
+///   matters.
+/// - Reviews of a moved card that are older than the cursor of its new deck are not returned,
+///   even if they were never returned for its old deck.
+///
+/// The cursor serializes to JSON. Save it only once the fetched reviews have been stored, so that
+/// a failed run is retried from the same position.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), ankiconnect::Error> {
+/// use std::path::Path;
+/// use ankiconnect::Client;
+/// use ankiconnect::operations::ReviewLogCursor;
+///
+/// let client = Client::default();
+/// let path = Path::new("cursor.json");
+///
+/// let mut cursor = if path.exists() { ReviewLogCursor::read(path)? } else { ReviewLogCursor::default() };
+/// for deck in cursor.fetch(&client).await?.decks {
+///     println!("{}: {} new reviews", deck.deck_name, deck.reviews.len());
+/// }
+/// cursor.write(path)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReviewLogCursor {
+    decks: BTreeMap<u64, DeckCursor>,
+}
+
+/// The position of a [`ReviewLogCursor`] in a single deck.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DeckCursor {
+
+    /// The name the deck had when it was last seen
+    pub name: String,
+
+    /// The ID of the latest review returned, or 0 if none have been
+    pub last_review_id: i64,
+
+    /// When the deck was first found to be missing, in milliseconds from UNIX epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<i64>,
+}
+
+/// The result of [`ReviewLogCursor::fetch`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReviewLogBatch {
+
+    /// New reviews in each deck that has any
+    pub decks: Vec<DeckReviews>,
+
+    /// IDs of decks seen for the first time
+    pub added_decks: Vec<u64>,
+
+    /// IDs of decks found to be deleted since the previous fetch
+    pub deleted_decks: Vec<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeckReviews {
+    pub deck_id: u64,
+    pub deck_name: String,
+    /// In ascending order of review ID
+    pub reviews: Vec<CardReview>,
+}
+
+impl ReviewLogBatch {
+    pub fn is_empty(&self) -> bool {
+        self.decks.is_empty() && self.added_decks.is_empty() && self.deleted_decks.is_empty()
+    }
+}
+
+impl ReviewLogCursor {
+
+    pub fn decks(&self) -> &BTreeMap<u64, DeckCursor> {
+        &self.decks
+    }
+
+    pub fn deck(&self, deck_id: u64) -> Option<&DeckCursor> {
+        self.decks.get(&deck_id)
+    }
+
+    /// Fetches the reviews added to each deck since the previous fetch, and advances the cursor
+    /// past them.
+    /// Decks whose latest review has already been returned are skipped without fetching their
+    /// review log. On error, the cursor is left unchanged.
+    pub async fn fetch(&mut self, client: &Client<'_>) -> Result<ReviewLogBatch, Error> {
+        let current = client.invoke_default::<DeckNamesAndIds>().await?;
+        let now = ReviewTimestamp::from_system_time(SystemTime::now()).as_millis();
+
+        let mut next = self.clone();
+        let (added_decks, deleted_decks) = next.update_decks(&current, now);
+
+        let mut decks = Vec::new();
+        for (&deck_id, deck) in next.decks.iter_mut().filter(|(_, x)| x.deleted_at.is_none()) {
+            let latest = client.invoke(&GetLatestReviewId::new(&deck.name)).await?;
+            let latest = i64::try_from(latest).unwrap_or(i64::MAX);
+            if latest <= deck.last_review_id {
+                continue;
+            }
+
+            let start = u64::try_from(deck.last_review_id).unwrap_or(0);
+            let mut reviews: Vec<CardReview> = client.invoke(&CardReviews::new(&deck.name, start)).await?
+                .iter()
+                .map(CardReview::from_tuple)
+                .collect();
+            reviews.sort_by_key(|x| x.id);
+
+            let last = reviews.last().map_or(latest, |x| x.id.max(latest));
+            deck.last_review_id = last;
+
+            if !reviews.is_empty() {
+                decks.push(DeckReviews {
+                    deck_id,
+                    deck_name: deck.name.clone(),
+                    reviews,
+                });
+            }
+        }
+
+        *self = next;
+        Ok(ReviewLogBatch {
+            decks,
+            added_decks,
+            deleted_decks,
+        })
+    }
+
+    /// Brings the tracked decks in line with `current`, a map of deck names to IDs.
+    /// Returns the IDs of added and newly deleted decks.
+    fn update_decks(&mut self, current: &HashMap<String, u64>, now: i64) -> (Vec<u64>, Vec<u64>) {
+        let mut added = Vec::new();
+        for (name, &id) in current {
+            match self.decks.get_mut(&id) {
+                Some(deck) => {
+                    deck.name.clone_from(name);
+                    deck.deleted_at = None;
+                },
+                None => {
+                    self.decks.insert(id, DeckCursor {
+                        name: name.clone(),
+                        last_review_id: 0,
+                        deleted_at: None,
+                    });
+                    added.push(id);
+                },
+            }
+        }
+        added.sort_unstable();
+
+        let mut deleted = Vec::new();
+        for (&id, deck) in self.decks.iter_mut() {
+            if deck.deleted_at.is_none() && !current.values().any(|x| *x == id) {
+                deck.deleted_at = Some(now);
+                deleted.push(id);
+            }
+        }
+
+        (added, deleted)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        let text = serde_json::to_string_pretty(self).map_err(Error::SerializeSerde)?;
+        std::fs::write(path, text).map_err(Error::Io)
+    }
+
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        let text = std::fs::read_to_string(path).map_err(Error::Io)?;
+        serde_json::from_str(&text).map_err(Error::DeserializeSerde)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decks(names: &[(&str, u64)]) -> HashMap<String, u64> {
+        names.iter().map(|(name, id)| (name.to_string(), *id)).collect()
+    }
+
+    #[test]
+    fn added_renamed_and_deleted_decks() {
+        let mut cursor = ReviewLogCursor::default();
+
+        let (added, deleted) = cursor.update_decks(&decks(&[("Default", 1), ("Spanish", 2)]), 100);
+        assert_eq!((added, deleted), (vec![1, 2], vec![]));
+        cursor.decks.get_mut(&2).unwrap().last_review_id = 50;
+
+        let (added, deleted) = cursor.update_decks(&decks(&[("Languages::Spanish", 2), ("French", 3)]), 200);
+        assert_eq!((added, deleted), (vec![3], vec![1]));
+        assert_eq!(cursor.deck(1).unwrap().deleted_at, Some(200));
+        assert_eq!(cursor.deck(2).unwrap().name, "Languages::Spanish");
+        assert_eq!(cursor.deck(2).unwrap().last_review_id, 50);
+
+        // A deck is only reported as deleted once
+        let (added, deleted) = cursor.update_decks(&decks(&[("Languages::Spanish", 2), ("French", 3)]), 300);
+        assert_eq!((added, deleted), (vec![], vec![]));
+        assert_eq!(cursor.deck(1).unwrap().deleted_at, Some(200));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut cursor = ReviewLogCursor::default();
+        cursor.update_decks(&decks(&[("Default", 1)]), 100);
+        cursor.update_decks(&decks(&[("Spanish", 2)]), 200);
+
+        let value = serde_json::to_value(&cursor).unwrap();
+        assert_eq!(value, serde_json::json!({
+            "decks": {
+                "1": {"name": "Default", "last_review_id": 0, "deleted_at": 200},
+                "2": {"name": "Spanish", "last_review_id": 0},
+            },
+        }));
+        assert_eq!(serde_json::from_value::<ReviewLogCursor>(value).unwrap(), cursor);
+    }
+
+}
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use crate::Client;
+use crate::endpoints::{
+    CardReviews,
+    CardsInfo,
+    DeckNamesAndIds,
+    GetLatestReviewId,
+    GetReviewsOfCards,
+    InsertReviews,
+};
+use crate::error::Error;
+use crate::models::card_review::CardReview;
+
+// Review operations
+
+/// Fetches the review log of each card, with card IDs filled in, in ascending order of review ID.
+pub async fn fetch_reviews_of_cards(client: &Client<'_>, cards: &[u64]) -> Result<Vec<CardReview>, Error> {
+    let reviews = client.invoke(&GetReviewsOfCards::new(cards)).await?;
+    let mut reviews: Vec<CardReview> = reviews
+        .iter()
+        .flat_map(|(card, x)| x.iter().map(|x| x.with_card_id(*card as i64)))
+        .collect();
+    reviews.sort_by_key(|x| x.id);
+    Ok(reviews)
+}
+
+/// Validates `request`, checks that every card it refers to exists and that none of its review
+/// IDs is already in the collection's review log, then sends it.
+/// Nothing is inserted if any check fails.
+///
+/// Review IDs are looked up in the review log of every deck. Reviews of deleted cards are not in
+/// any deck, so an ID used by one of them is not caught.
+pub async fn insert_reviews(client: &Client<'_>, request: &InsertReviews<'_>) -> Result<(), Error> {
+    let existing = dry_run_insert_reviews(client, request).await?;
+    if !existing.is_empty() {
+        let ids: Vec<String> = existing.iter().map(|x| x.id.to_string()).collect();
+        return Err(Error::Invalid(format!("review IDs already in the review log: {}", ids.join(", "))));
+    }
+    client.invoke(request).await
+}
+
+/// Runs the same checks as [`insert_reviews`] without inserting anything, and returns the reviews
+/// whose IDs are already in the collection's review log instead of failing on them.
+pub async fn dry_run_insert_reviews(
+    client: &Client<'_>,
+    request: &InsertReviews<'_>,
+) -> Result<Vec<CardReview>, Error> {
+    check_insert_reviews(client, request).await?;
+    let Some(start) = request.reviews().iter().map(|x| x.0).min() else {
+        return Ok(Vec::new());
+    };
+    let existing = fetch_review_ids_since(client, start).await?;
+    Ok(request.reviews()
+        .iter()
+        .filter(|x| existing.contains(&x.0))
+        .map(CardReview::from_tuple)
+        .collect())
+}
+
+/// The IDs of every review from `start` on, read from the review log of each deck.
+/// Reviews of deleted cards are not in any deck and are left out.
+pub(crate) async fn fetch_review_ids_since(client: &Client<'_>, start: i64) -> Result<HashSet<i64>, Error> {
+    let decks = client.invoke_default::<DeckNamesAndIds>().await?;
+    // `cardReviews` returns the reviews after the given ID
+    let after = u64::try_from(start.saturating_sub(1)).unwrap_or(0);
+
+    let mut ids = HashSet::new();
+    for deck in decks.keys() {
+        let latest = client.invoke(&GetLatestReviewId::new(deck)).await?;
+        if latest <= after {
+            continue;
+        }
+        let reviews = client.invoke(&CardReviews::new(deck, after)).await?;
+        ids.extend(reviews.iter().map(|x| x.0));
+    }
+    Ok(ids)
+}
+
+/// Validates `request` and checks that every card it refers to exists.
+async fn check_insert_reviews(client: &Client<'_>, request: &InsertReviews<'_>) -> Result<(), Error> {
+    request.validate()?;
+
+    // `validate` rejects card IDs that are not positive
+    let mut cards: Vec<u64> = request.reviews()
+        .iter()
+        .map(|x| x.1 as u64)
+        .collect();
+    cards.sort_unstable();
+    cards.dedup();
+
+    if cards.is_empty() {
+        return Ok(());
+    }
+
+    let info = client.invoke(&CardsInfo::new(&cards[..])).await?;
+    let missing: Vec<String> = cards
+        .iter()
+        .zip(info)
+        .filter(|(_, x)| x.0.is_none())
+        .map(|(card, _)| card.to_string())
+        .collect();
+
+    if !missing.is_empty() {
+        return Err(Error::Invalid(format!("cards do not exist: {}", missing.join(", "))));
+    }
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use crate::Client;
+use crate::endpoints::GetNumCardsReviewedByDay;
+use crate::error::Error;
+use crate::models::DailyCounts;
+
+// Statistic operations
+
+/// The number of review log entries per day, as reported by `getNumCardsReviewedByDay`.
+pub async fn fetch_reviewed_by_day(client: &Client<'_>) -> Result<DailyCounts, Error> {
+    let reported = client.invoke_default::<GetNumCardsReviewedByDay>().await?;
+    #[cfg(feature = "chrono")]
+    let counts = Ok(reported.into_iter().map(|(day, count)| (day.into(), count)).collect());
+    #[cfg(not(feature = "chrono"))]
+    let counts = DailyCounts::from_reported(&reported);
+    counts
+}
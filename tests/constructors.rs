@@ -0,0 +1,153 @@
+// Builds every endpoint from outside the crate, so that a request which can't be constructed by
+// users fails to compile here. Nothing is sent to Anki; these run without an Anki instance.
+//
+// When adding an endpoint, add it below.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use ankiconnect::endpoints::request::Request;
+use ankiconnect::endpoints::*;
+use ankiconnect::models::{CardTemplate, DueDateSpec, Ease, MediaSource, Note};
+
+fn request<R: Request>(_: R) {}
+
+#[test]
+fn card_actions() {
+    request(GetEaseFactors::new(&[1]));
+    request(AreDue::new(&[1]));
+    request(GetIntervals::new(&[1]));
+    request(GetIntervalHistory::new(&[1]));
+    request(FindCards::new("deck:Default"));
+    request(CardsToNotes::new(&[1]));
+    request(CardsInfo::new(&[1]));
+    request(ForgetCards::new(&[1]).with_restore_position(true).with_reset_counts(false));
+    request(RelearnCards::new(&[1]));
+    request(ForgetCards::default());
+    request(RelearnCards::default());
+    request(AnswerCards::new(vec![(1, Ease::Good)]));
+    request(SetDueDate::new(&[1], DueDateSpec::today()));
+}
+
+#[test]
+fn deck_actions() {
+    let config = serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "name": "Default",
+        "new": {"delays": [1.0], "perDay": 20, "ints": [1, 4, 0], "initialFactor": 2500},
+        "lapse": {"delays": [10.0], "minInt": 1, "mult": 0.0, "leechFails": 8, "leechAction": 0},
+        "rev": {"perDay": 200, "ease4": 1.3, "ivlFct": 1.0, "maxIvl": 36500},
+    })).unwrap();
+
+    request(DeckNames);
+    request(DeckNamesAndIds);
+    request(GetDecks::new(&[1]));
+    request(CreateDeck::new("Default"));
+    request(ChangeDeck::new(&[1], "Default"));
+    request(DeleteDecks::single("Default"));
+    request(DeleteDecks::new(vec!["Default".into()]).with_cards_too(true));
+    request(GetDeckConfig::new("Default"));
+    request(SaveDeckConfig::new(&config));
+    request(SetDeckConfigId::single("Default", 1));
+    request(CloneDeckConfigId::new("Copy", 1));
+    request(RemoveDeckConfigId::new(1));
+    request(GetDeckStats::single("Default"));
+}
+
+#[test]
+fn gui_actions() {
+    request(GuiBrowse::builder().query("deck:Default").build());
+    request(GuiSelectCard::new(1));
+    request(GuiSelectNote::new(1));
+    request(GuiSelectedNotes);
+    request(GuiAddCards::new(Note::new("Default", "Basic").field("Front", "front")).with_close_after_adding(true));
+    request(GuiEditNote::new(1));
+    request(GuiCurrentCard);
+    request(GuiStartCardTimer);
+    request(GuiShowQuestion);
+    request(GuiShowAnswer);
+    request(GuiAnswerCard::new(Ease::Good));
+    request(GuiPlayAudio);
+    request(GuiUndo);
+    request(GuiDeckOverview::new("Default"));
+    request(GuiDeckBrowser);
+    request(GuiDeckReview::new("Default"));
+    request(GuiImportFile::new("/tmp/deck.apkg"));
+    request(GuiImportFile::default());
+    request(GuiExitAnki);
+    request(GuiCheckDatabase);
+}
+
+#[test]
+fn media_actions() {
+    let source = MediaSource::Url(Cow::Owned("https://example.com/a.mp3".to_string()));
+    request(StoreMediaFile::new("a.mp3", source).with_delete_existing(true).with_skip_hash("d41d8cd98f00b204e9800998ecf8427e"));
+    request(RetrieveMediaFile::new("a.mp3"));
+    request(GetMediaFilesNames::all());
+    request(GetMediaDirPath);
+    request(DeleteMediaFile::new("a.mp3"));
+}
+
+#[test]
+fn misc_actions() {
+    request(Version);
+    request(Sync);
+    request(GetProfiles);
+    request(GetActiveProfile);
+    request(ApiReflect::supported(["version".into()]));
+    request(LoadProfile::new("User 1"));
+    request(ExportPackage::new("Default", "/tmp/deck.apkg").with_include_sched(true));
+    request(ImportPackage::new("/tmp/deck.apkg"));
+    request(ReloadCollection);
+}
+
+#[test]
+fn model_actions() {
+    let template = CardTemplate::new("{{Front}}", "{{Back}}");
+
+    request(ModelNames);
+    request(ModelNamesAndIds);
+    request(FindModelsById::new(vec![1]));
+    request(FindModelsByName::single("Basic"));
+    request(ModelFieldNames::new("Basic"));
+    request(ModelFieldDescriptions::new("Basic"));
+    request(ModelFieldFonts::new("Basic"));
+    request(ModelFieldsOnTemplates::new("Basic"));
+    request(CreateModel::builder("Basic").field("Front").field("Back").template("Card 1", template.clone()).build().unwrap());
+    request(ModelTemplates::new("Basic"));
+    request(ModelStyling::new("Basic"));
+    request(UpdateModelTemplates::new("Basic", HashMap::from([("Card 1".into(), Cow::Borrowed(&template))])));
+    request(UpdateModelTemplates::single("Basic", "Card 1", template.clone()));
+    request(UpdateModelStyling::new("Basic", ".card {}"));
+    request(FindAndReplaceInModels::all_models("old", "new").with_css(false));
+    request(ModelTemplateRename::new("Basic", "Card 1", "Forward"));
+    request(ModelTemplateReposition::new("Basic", "Card 1", 0));
+    request(ModelTemplateAdd::new("Basic", "Card 2", template));
+    request(ModelTemplateRemove::new("Basic", "Card 2"));
+    request(ModelFieldRename::new("Basic", "Front", "Question"));
+    request(ModelFieldReposition::new("Basic", "Front", 1));
+    request(ModelFieldAdd::new("Basic", "Extra", None));
+    request(ModelFieldRemove::new("Basic", "Extra"));
+    request(ModelFieldSetFont::new("Basic", "Front", "Arial"));
+    request(ModelFieldSetFontSize::new("Basic", "Front", 20));
+    request(ModelFieldSetDescription::new("Basic", "Front", "The question"));
+}
+
+#[test]
+fn note_actions() {
+    request(AddTags::new(&[1], "tag"));
+    request(RemoveTags::new(&[1], "tag"));
+    request(FindNotes::new("deck:Default"));
+}
+
+#[test]
+fn statistic_actions() {
+    request(GetNumCardsReviewedToday);
+    request(GetNumCardsReviewedByDay);
+    request(GetCollectionStatsHtml::new(true));
+    request(CardReviews::new("Default", 0));
+    request(GetReviewsOfCards::new(&[1]));
+    request(GetLatestReviewId::new("Default"));
+    request(InsertReviews::new(vec![(1, 1, -1, 3, 1, -60, 2500, 6000, 1)].into()));
+    request(InsertReviews::new_from(&[]));
+}
@@ -0,0 +1,17 @@
+use ankiconnect::Client;
+use ankiconnect::endpoints::ApiReflect;
+
+#[tokio::test]
+async fn test_api_reflect_default() {
+    let client = Client::default();
+    let result = client.invoke_default::<ApiReflect>().await.unwrap();
+    assert!(result.actions.iter().any(|x| x == "version"));
+}
+
+#[tokio::test]
+async fn test_api_reflect_supported() {
+    let client = Client::default();
+    let request = ApiReflect::supported(["version".into(), "notARealAction".into()]);
+    let result = client.invoke(&request).await.unwrap();
+    assert_eq!(result.actions, vec!["version".to_string()]);
+}
@@ -0,0 +1,30 @@
+use ankiconnect::Client;
+use ankiconnect::endpoints::{FindModelsById, FindModelsByName, ModelNames, ModelNamesAndIds};
+
+#[tokio::test]
+async fn test_find_models_by_name() {
+    let client = Client::default();
+
+    let model_names = client.invoke_default::<ModelNames>().await.unwrap();
+
+    let request = FindModelsByName::new(
+        model_names.iter().map(|x| x.as_str().into()).collect::<Vec<_>>()
+    );
+    let models = client.invoke(&request).await.unwrap();
+
+    assert_eq!(models.len(), model_names.len());
+}
+
+#[tokio::test]
+async fn test_find_models_by_id() {
+    let client = Client::default();
+
+    let models = client.invoke_default::<ModelNamesAndIds>().await.unwrap();
+
+    for (name, id) in models {
+        let request = FindModelsById::new(vec![id]);
+        let found = client.invoke(&request).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, name);
+    }
+}
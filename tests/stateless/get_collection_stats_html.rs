@@ -1,5 +1,6 @@
 use ankiconnect::Client;
 use ankiconnect::endpoints::{GetCollectionStatsHtml};
+use ankiconnect::models::CollectionStats;
 
 #[tokio::test]
 async fn test_get_collection_stats_default() {
@@ -20,3 +21,12 @@ async fn test_get_collection_stats_not_whole_collection() {
     let request = GetCollectionStatsHtml::new(false);
     client.invoke(&request).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_parse_collection_stats() {
+    let client = Client::default();
+    let request = GetCollectionStatsHtml::new(true);
+    let html = client.invoke(&request).await.unwrap();
+    let stats = CollectionStats::parse(&html);
+    assert!(stats.card_counts.total_cards.is_some(), "missing: {:?}", stats.missing);
+}
@@ -0,0 +1,22 @@
+use ankiconnect::Client;
+use ankiconnect::endpoints::{DeckNames, GetDeckConfig};
+
+#[tokio::test]
+async fn test_get_deck_config_default() {
+    let client = Client::default();
+    let request = GetDeckConfig::new("Default");
+    let config = client.invoke(&request).await.unwrap();
+    assert!(config.into_option().is_some());
+}
+
+#[tokio::test]
+async fn test_get_deck_config_on_every_deck() {
+    let client = Client::default();
+
+    let decks = client.invoke_default::<DeckNames>().await.unwrap();
+
+    for deck in decks {
+        let request = GetDeckConfig::new(deck);
+        client.invoke(&request).await.unwrap();
+    }
+}
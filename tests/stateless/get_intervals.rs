@@ -0,0 +1,25 @@
+use ankiconnect::Client;
+use ankiconnect::endpoints::{FindCards, GetIntervals, GetIntervalHistory};
+
+#[tokio::test]
+async fn test_get_intervals_default() {
+    let client = Client::default();
+    let result = client.invoke_default::<GetIntervals>().await.unwrap();
+    assert_eq!(result.len(), 0);
+}
+
+#[tokio::test]
+async fn test_get_intervals_for_a_few_cards() {
+    let client = Client::default();
+
+    let cards = client.invoke_default::<FindCards>().await.unwrap();
+    let cards = &cards[..cards.len().min(3)];
+
+    let request = GetIntervals::new(cards);
+    let intervals = client.invoke(&request).await.unwrap();
+    assert_eq!(intervals.len(), cards.len());
+
+    let request = GetIntervalHistory::new(cards);
+    let history = client.invoke(&request).await.unwrap();
+    assert_eq!(history.len(), cards.len());
+}
@@ -0,0 +1,8 @@
+use ankiconnect::Client;
+use ankiconnect::endpoints::GuiCurrentCard;
+
+#[tokio::test]
+async fn test_gui_current_card() {
+    let client = Client::default();
+    client.invoke_default::<GuiCurrentCard>().await.unwrap();
+}
@@ -0,0 +1,8 @@
+use ankiconnect::Client;
+use ankiconnect::endpoints::GuiSelectedNotes;
+
+#[tokio::test]
+async fn test_gui_selected_notes() {
+    let client = Client::default();
+    client.invoke_default::<GuiSelectedNotes>().await.unwrap();
+}
@@ -1,13 +1,21 @@
+pub mod api_reflect;
 pub mod are_due;
 pub mod card_reviews;
 pub mod find_cards;
+pub mod find_models;
 pub mod get_collection_stats_html;
+pub mod get_deck_config;
 pub mod get_deck_stats;
 pub mod get_ease_factors;
+pub mod get_intervals;
 pub mod get_latest_review_id;
 pub mod get_media_dir_path;
 pub mod get_media_files_names;
 pub mod get_num_cards_reviewed_by_day;
 pub mod get_reviews_of_cards;
+pub mod gui_current_card;
+pub mod gui_selected_notes;
+pub mod model_fields;
 pub mod model_styling;
+pub mod model_templates;
 pub mod version;
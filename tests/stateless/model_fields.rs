@@ -0,0 +1,31 @@
+use ankiconnect::Client;
+use ankiconnect::endpoints::{
+    ModelFieldDescriptions,
+    ModelFieldFonts,
+    ModelFieldNames,
+    ModelFieldsOnTemplates,
+    ModelNames,
+};
+
+#[tokio::test]
+async fn test_model_fields() {
+    let client = Client::default();
+
+    let model_names = client.invoke_default::<ModelNames>().await.unwrap();
+
+    for model_name in model_names {
+        let request = ModelFieldNames::new(&model_name);
+        let names = client.invoke(&request).await.unwrap();
+
+        let request = ModelFieldDescriptions::new(&model_name);
+        let descriptions = client.invoke(&request).await.unwrap();
+        assert_eq!(descriptions.len(), names.len());
+
+        let request = ModelFieldFonts::new(&model_name);
+        let fonts = client.invoke(&request).await.unwrap();
+        assert_eq!(fonts.len(), names.len());
+
+        let request = ModelFieldsOnTemplates::new(&model_name);
+        client.invoke(&request).await.unwrap();
+    }
+}
@@ -0,0 +1,15 @@
+use ankiconnect::Client;
+use ankiconnect::endpoints::{ModelNames, ModelTemplates};
+
+#[tokio::test]
+async fn test_model_templates() {
+    let client = Client::default();
+
+    let model_names = client.invoke_default::<ModelNames>().await.unwrap();
+
+    for model_name in model_names {
+        let request = ModelTemplates::new(model_name);
+        let templates = client.invoke(&request).await.unwrap();
+        assert!(!templates.is_empty());
+    }
+}